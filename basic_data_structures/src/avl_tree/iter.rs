@@ -0,0 +1,176 @@
+use std::{borrow::Borrow, iter::FusedIterator, ops::Bound, ptr};
+
+use super::Node;
+use crate::bounds::above;
+
+/// Walks a tree in order with an explicit stack holding the nodes whose left subtree has been
+/// visited but which haven't been yielded yet.
+struct InOrder<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+}
+
+impl<'a, K, V> InOrder<'a, K, V> {
+    fn push_left(&mut self, mut link: Option<&'a Node<K, V>>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = node.left.as_deref();
+        }
+    }
+
+    fn next(&mut self) -> Option<&'a Node<K, V>> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        Some(node)
+    }
+}
+
+impl<K, V> Clone for InOrder<'_, K, V> {
+    fn clone(&self) -> Self {
+        InOrder { stack: self.stack.clone() }
+    }
+}
+
+/// An iterator over the entries of an `AvlTree`, in ascending key order.
+///
+/// Created by `AvlTree::iter`.
+pub struct Iter<'a, K, V> {
+    nodes: InOrder<'a, K, V>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    pub(super) fn new(root: Option<&'a Node<K, V>>, size: usize) -> Self {
+        let mut nodes = InOrder { stack: Vec::new() };
+        nodes.push_left(root);
+        Iter { nodes, remaining: size }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let node = self.nodes.next()?;
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Iter { nodes: self.nodes.clone(), remaining: self.remaining }
+    }
+}
+
+/// An iterator over the keys of an `AvlTree`, in ascending order.
+///
+/// Created by `AvlTree::keys`.
+pub struct Keys<'a, K, V> {
+    pub(super) iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+/// An iterator over the values of an `AvlTree`, in ascending key order.
+///
+/// Created by `AvlTree::values`.
+pub struct Values<'a, K, V> {
+    pub(super) iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+/// An iterator over the entries of an `AvlTree` whose keys are within a range, in ascending
+/// key order.
+///
+/// Created by `AvlTree::range`.
+pub struct Range<'a, K, V> {
+    nodes: InOrder<'a, K, V>,
+    /// The key of the last entry in the range, or `None` once it has been yielded.
+    last: Option<&'a K>,
+}
+
+impl<'a, K, V> Range<'a, K, V> {
+    /// Creates an iterator that starts at the first key within `start` and stops after
+    /// yielding `last`, which must be a key of the tree.
+    pub(super) fn new<Q>(root: Option<&'a Node<K, V>>, start: Bound<&Q>, last: &'a K) -> Self
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        // Only the nodes at or after the start of the range go on the stack: the ones before
+        // it are skipped along with their left subtrees.
+        let mut nodes = InOrder { stack: Vec::new() };
+        let mut link = root;
+        while let Some(node) = link {
+            if above(node.key.borrow(), start) {
+                nodes.stack.push(node);
+                link = node.left.as_deref();
+            } else {
+                link = node.right.as_deref();
+            }
+        }
+        Range { nodes, last: Some(last) }
+    }
+
+    pub(super) fn empty() -> Self {
+        Range { nodes: InOrder { stack: Vec::new() }, last: None }
+    }
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let last = self.last?;
+        let node = self.nodes.next()?;
+        if ptr::eq(&node.key, last) {
+            self.last = None;
+        }
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<K, V> FusedIterator for Range<'_, K, V> {}
+
+impl<K, V> Clone for Range<'_, K, V> {
+    fn clone(&self) -> Self {
+        Range { nodes: self.nodes.clone(), last: self.last }
+    }
+}
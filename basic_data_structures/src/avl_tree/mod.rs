@@ -0,0 +1,456 @@
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    mem,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::bounds::{above, below};
+
+pub mod iter;
+use iter::{Iter, Keys, Range, Values};
+
+#[derive(Clone)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    height: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+impl<K, V> Node<K, V> {
+    fn new(key: K, value: V) -> Box<Self> {
+        Box::new(Node { key, value, height: 1, left: None, right: None })
+    }
+
+    fn update_height(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+    }
+
+    /// The height of the left subtree minus the height of the right one.
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+fn height<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn rotate_right<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut left = node.left.take().expect("rotating right needs a left child");
+    node.left = left.right.take();
+    node.update_height();
+    left.right = Some(node);
+    left.update_height();
+    left
+}
+
+fn rotate_left<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut right = node.right.take().expect("rotating left needs a right child");
+    node.right = right.left.take();
+    node.update_height();
+    right.left = Some(node);
+    right.update_height();
+    right
+}
+
+/// Restores the AVL invariant of `node`, whose subtrees are balanced but may differ in height
+/// by two after an insertion or a removal.
+fn rebalance<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    node.update_height();
+    let balance = node.balance_factor();
+    if balance > 1 {
+        if node.left.as_ref().is_some_and(|left| left.balance_factor() < 0) {
+            node.left = node.left.take().map(rotate_left);
+        }
+        rotate_right(node)
+    } else if balance < -1 {
+        if node.right.as_ref().is_some_and(|right| right.balance_factor() > 0) {
+            node.right = node.right.take().map(rotate_right);
+        }
+        rotate_left(node)
+    } else {
+        node
+    }
+}
+
+fn insert<K: Ord, V>(link: Link<K, V>, key: K, value: V) -> (Box<Node<K, V>>, Option<V>) {
+    let mut node = match link {
+        None => return (Node::new(key, value), None),
+        Some(node) => node,
+    };
+    match key.cmp(&node.key) {
+        Ordering::Less => {
+            let (left, old) = insert(node.left.take(), key, value);
+            node.left = Some(left);
+            (rebalance(node), old)
+        }
+        Ordering::Greater => {
+            let (right, old) = insert(node.right.take(), key, value);
+            node.right = Some(right);
+            (rebalance(node), old)
+        }
+        Ordering::Equal => {
+            let old = mem::replace(&mut node.value, value);
+            (node, Some(old))
+        }
+    }
+}
+
+fn remove<K, V, Q>(link: Link<K, V>, key: &Q) -> (Link<K, V>, Option<(K, V)>)
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let mut node = match link {
+        None => return (None, None),
+        Some(node) => node,
+    };
+    match key.cmp(node.key.borrow()) {
+        Ordering::Less => {
+            let (left, removed) = remove(node.left.take(), key);
+            node.left = left;
+            (Some(rebalance(node)), removed)
+        }
+        Ordering::Greater => {
+            let (right, removed) = remove(node.right.take(), key);
+            node.right = right;
+            (Some(rebalance(node)), removed)
+        }
+        Ordering::Equal => {
+            let replacement = match (node.left.take(), node.right.take()) {
+                (None, child) | (child, None) => child,
+                (Some(left), Some(right)) => {
+                    // The in-order successor takes the place of the removed node.
+                    let (right, mut successor) = remove_min(right);
+                    successor.left = Some(left);
+                    successor.right = right;
+                    Some(rebalance(successor))
+                }
+            };
+            let Node { key, value, .. } = *node;
+            (replacement, Some((key, value)))
+        }
+    }
+}
+
+/// Detaches the smallest node of the subtree rooted at `node`.
+///
+/// # Returns
+///
+/// The rebalanced rest of the subtree and the detached node.
+fn remove_min<K, V>(mut node: Box<Node<K, V>>) -> (Link<K, V>, Box<Node<K, V>>) {
+    match node.left.take() {
+        Some(left) => {
+            let (left, min) = remove_min(left);
+            node.left = left;
+            (Some(rebalance(node)), min)
+        }
+        None => (node.right.take(), node),
+    }
+}
+
+/// An ordered map stored as an AVL tree.
+///
+/// The heights of the two subtrees of every node differ by at most one, so the tree stays
+/// balanced whatever the insertion order and `insert`, `remove` and `get` are O(log n). Keys
+/// are kept sorted, which makes `min`/`max`, `floor`/`ceiling` and `range` queries cheap, and
+/// every iterator visits the entries in ascending key order.
+///
+/// # Type parameters
+///
+/// * `K`: The type of the keys, which must implement `Ord`.
+/// * `V`: The type of the values.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::avl_tree::AvlTree;
+///
+/// let mut timeline = AvlTree::new();
+/// timeline.insert(30, "fade out");
+/// timeline.insert(10, "fade in");
+/// timeline.insert(20, "spin");
+///
+/// assert_eq!(timeline.floor(&25), Some((&20, &"spin")));
+/// assert_eq!(timeline.ceiling(&25), Some((&30, &"fade out")));
+/// let events: Vec<_> = timeline.range(10..30).map(|(_, event)| *event).collect();
+/// assert_eq!(events, vec!["fade in", "spin"]);
+/// ```
+#[derive(Clone)]
+pub struct AvlTree<K, V> {
+    root: Link<K, V>,
+    size: usize,
+}
+
+impl<K, V> AvlTree<K, V> {
+    /// Creates a new, empty tree.
+    pub fn new() -> Self {
+        AvlTree { root: None, size: 0 }
+    }
+
+    /// Returns the number of entries in the tree.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Checks whether the tree is empty.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Removes every entry from the tree.
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.size = 0;
+    }
+
+    /// Returns the number of nodes on the longest path from the root to a leaf, `0` for an
+    /// empty tree.
+    ///
+    /// An AVL tree with `n` entries is never higher than about `1.44 * log2(n + 2)`.
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Returns the entry with the smallest key.
+    pub fn min(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+        Some((&node.key, &node.value))
+    }
+
+    /// Returns the entry with the largest key.
+    pub fn max(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+        Some((&node.key, &node.value))
+    }
+
+    /// Returns an iterator over the entries of the tree, in ascending key order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.root.as_deref(), self.size)
+    }
+
+    /// Returns an iterator over the keys of the tree, in ascending order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Returns an iterator over the values of the tree, in ascending key order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+}
+
+impl<K: Ord, V> AvlTree<K, V> {
+    /// Inserts a key/value pair into the tree.
+    ///
+    /// # Returns
+    ///
+    /// The previous value of `key`, if it was already in the tree. The key itself is not
+    /// updated.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (root, old) = insert(self.root.take(), key, value);
+        self.root = Some(root);
+        if old.is_none() {
+            self.size += 1;
+        }
+        old
+    }
+
+    /// Removes `key` from the tree.
+    ///
+    /// # Returns
+    ///
+    /// The value that was associated with `key`, if any.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (root, removed) = remove(self.root.take(), key);
+        self.root = root;
+        let (_, value) = removed?;
+        self.size -= 1;
+        Some(value)
+    }
+
+    /// Returns a reference to the value associated with `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut link = self.root.as_deref();
+        while let Some(node) = link {
+            link = match key.cmp(node.key.borrow()) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+        None
+    }
+
+    /// Returns a mutable reference to the value associated with `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut link = self.root.as_deref_mut();
+        while let Some(node) = link {
+            link = match key.cmp(node.key.borrow()) {
+                Ordering::Less => node.left.as_deref_mut(),
+                Ordering::Greater => node.right.as_deref_mut(),
+                Ordering::Equal => return Some(&mut node.value),
+            };
+        }
+        None
+    }
+
+    /// Returns `true` if the tree contains `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Returns the entry with the largest key that is less than or equal to `key`.
+    pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = last_in(self.root.as_deref(), Bound::Included(key))?;
+        Some((&node.key, &node.value))
+    }
+
+    /// Returns the entry with the smallest key that is greater than or equal to `key`.
+    pub fn ceiling<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = first_in(self.root.as_deref(), Bound::Included(key))?;
+        Some((&node.key, &node.value))
+    }
+
+    /// Returns an iterator over the entries whose keys are within `range`, in ascending key
+    /// order.
+    ///
+    /// ```
+    /// use basic_data_structures::avl_tree::AvlTree;
+    ///
+    /// let tree: AvlTree<u32, ()> = (0..10).map(|i| (i, ())).collect();
+    /// let keys: Vec<_> = tree.range(3..=5).map(|(key, _)| *key).collect();
+    /// assert_eq!(keys, vec![3, 4, 5]);
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let root = self.root.as_deref();
+        match (first_in(root, range.start_bound()), last_in(root, range.end_bound())) {
+            (Some(first), Some(last)) if first.key <= last.key => {
+                Range::new(root, range.start_bound(), &last.key)
+            }
+            _ => Range::empty(),
+        }
+    }
+}
+
+/// Returns the node with the smallest key that is within the lower bound `start`.
+fn first_in<'a, K, V, Q>(mut link: Option<&'a Node<K, V>>, start: Bound<&Q>) -> Option<&'a Node<K, V>>
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let mut found = None;
+    while let Some(node) = link {
+        if above(node.key.borrow(), start) {
+            found = Some(node);
+            link = node.left.as_deref();
+        } else {
+            link = node.right.as_deref();
+        }
+    }
+    found
+}
+
+/// Returns the node with the largest key that is within the upper bound `end`.
+fn last_in<'a, K, V, Q>(mut link: Option<&'a Node<K, V>>, end: Bound<&Q>) -> Option<&'a Node<K, V>>
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    let mut found = None;
+    while let Some(node) = link {
+        if below(node.key.borrow(), end) {
+            found = Some(node);
+            link = node.right.as_deref();
+        } else {
+            link = node.left.as_deref();
+        }
+    }
+    found
+}
+
+impl<K, V> Default for AvlTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for AvlTree<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Display, V: Display> AvlTree<K, V> {
+    pub fn print(&self) {
+        let entries: Vec<String> =
+            self.iter().map(|(key, value)| format!("{}: {}", key, value)).collect();
+        println!("{{{}}}", entries.join(", "));
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for AvlTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for AvlTree<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a AvlTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
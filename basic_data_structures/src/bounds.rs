@@ -0,0 +1,19 @@
+use std::ops::Bound;
+
+/// Returns `true` if `key` is on the right side of the lower bound `start`.
+pub(crate) fn above<Q: Ord + ?Sized>(key: &Q, start: Bound<&Q>) -> bool {
+    match start {
+        Bound::Included(start) => key >= start,
+        Bound::Excluded(start) => key > start,
+        Bound::Unbounded => true,
+    }
+}
+
+/// Returns `true` if `key` is on the left side of the upper bound `end`.
+pub(crate) fn below<Q: Ord + ?Sized>(key: &Q, end: Bound<&Q>) -> bool {
+    match end {
+        Bound::Included(end) => key <= end,
+        Bound::Excluded(end) => key < end,
+        Bound::Unbounded => true,
+    }
+}
@@ -0,0 +1,210 @@
+use std::{cmp::Ordering, mem};
+
+/// A partition of the elements `0..len` into disjoint sets, also known as union-find.
+///
+/// Each set is a tree whose root represents it. `find` returns the root of an element and
+/// flattens the path it walked (path compression), and `union` hangs the shorter tree under the
+/// taller one (union by rank). Together they make both operations run in nearly constant
+/// amortized time.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::disjoint_set::DisjointSet;
+///
+/// let mut sets = DisjointSet::new(5);
+/// sets.union(0, 1);
+/// sets.union(3, 4);
+/// sets.union(1, 4);
+///
+/// assert!(sets.connected(0, 3));
+/// assert!(!sets.connected(0, 2));
+/// assert_eq!(sets.set_count(), 2);
+/// assert_eq!(sets.set_size(4), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    /// An upper bound on the height of the tree under each root.
+    ranks: Vec<u8>,
+    /// The number of elements in the set of each root.
+    sizes: Vec<usize>,
+    set_count: usize,
+}
+
+impl DisjointSet {
+    /// Creates `len` sets, each holding one of the elements `0..len`.
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            set_count: len,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Checks whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Adds a new element in a set of its own.
+    ///
+    /// # Returns
+    ///
+    /// The new element, which is the previous `len`.
+    pub fn make_set(&mut self) -> usize {
+        let element = self.len();
+        self.parents.push(element);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        self.set_count += 1;
+        element
+    }
+
+    /// Returns the representative of the set holding `element`. Two elements are in the same set
+    /// if and only if they have the same representative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element` is not below `len`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        // Point every element on the path straight at the root.
+        let mut current = element;
+        while current != root {
+            current = mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// Merges the sets holding `a` and `b`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if they were in different sets, `false` if they already were in the same one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not below `len`.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (root, child) = match self.ranks[a].cmp(&self.ranks[b]) {
+            Ordering::Less => (b, a),
+            Ordering::Greater => (a, b),
+            Ordering::Equal => {
+                self.ranks[a] += 1;
+                (a, b)
+            }
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        self.set_count -= 1;
+        true
+    }
+
+    /// Checks whether `a` and `b` are in the same set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not below `len`.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the number of elements in the set holding `element`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `element` is not below `len`.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Returns the sets, each as the list of its elements in ascending order. The sets are
+    /// ordered by their smallest element.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut sets: Vec<Vec<usize>> = Vec::with_capacity(self.set_count);
+        // The index in `sets` of the set of each root, once it has been seen.
+        let mut positions: Vec<Option<usize>> = vec![None; self.len()];
+        for element in 0..self.len() {
+            let root = self.find(element);
+            match positions[root] {
+                Some(position) => sets[position].push(element),
+                None => {
+                    positions[root] = Some(sets.len());
+                    sets.push(vec![element]);
+                }
+            }
+        }
+        sets
+    }
+}
+
+/// Finds a minimum spanning forest with Kruskal's algorithm, in O(E log E).
+///
+/// `edges` lists undirected `(a, b, weight)` edges between the nodes `0..node_count`. The edges
+/// are tried from the lightest to the heaviest, and each one is kept unless its ends are already
+/// connected by the edges kept so far. If the graph is connected, the result is a minimum
+/// spanning tree with `node_count - 1` edges; otherwise it spans each connected component.
+/// Weights are only compared, so they can be floats.
+///
+/// # Returns
+///
+/// The indices in `edges` of the edges that were kept, from the lightest to the heaviest.
+/// Edges of equal weight are kept in the order they are listed.
+///
+/// # Panics
+///
+/// Panics if an edge has an end that is not below `node_count`, or if two weights can't be
+/// compared, like a `NaN` with any float.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::disjoint_set::kruskal;
+///
+/// let edges = [(0, 1, 4), (1, 2, 1), (0, 2, 2), (2, 3, 7)];
+/// assert_eq!(kruskal(4, &edges), vec![1, 2, 3]);
+/// ```
+pub fn kruskal<W: PartialOrd>(node_count: usize, edges: &[(usize, usize, W)]) -> Vec<usize> {
+    // Sorting needs a total order, which a NaN breaks for every float it's compared with.
+    assert!(
+        edges.iter().all(|(_, _, weight)| weight.partial_cmp(weight).is_some()),
+        "kruskal can't sort NaN weights"
+    );
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|&a, &b| {
+        edges[a].2.partial_cmp(&edges[b].2).expect("kruskal needs weights that can be compared")
+    });
+
+    let mut sets = DisjointSet::new(node_count);
+    let mut forest = Vec::with_capacity(node_count.saturating_sub(1));
+    for index in order {
+        let (a, b, _) = edges[index];
+        if sets.union(a, b) {
+            forest.push(index);
+            if sets.set_count() == 1 {
+                break;
+            }
+        }
+    }
+    forest
+}
@@ -0,0 +1,180 @@
+use std::{iter::FusedIterator, marker::PhantomData};
+
+use super::{
+    node::{Link, Node},
+    DoublyLinkedList,
+};
+
+/// An iterator over references to the values of a `DoublyLinkedList`.
+///
+/// Created by `DoublyLinkedList::iter`.
+pub struct Iter<'a, T> {
+    pub(super) head: Link<T>,
+    pub(super) tail: Link<T>,
+    pub(super) remaining: usize,
+    pub(super) marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            // SAFETY: the list is borrowed for `'a` and `remaining` keeps the iterator from
+            // walking past the nodes that the back end already yielded.
+            let node = unsafe { &*node.as_ptr() };
+            self.head = node.next;
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.tail.map(|node| {
+            // SAFETY: see `next`.
+            let node = unsafe { &*node.as_ptr() };
+            self.tail = node.prev;
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// An iterator over mutable references to the values of a `DoublyLinkedList`.
+///
+/// Created by `DoublyLinkedList::iter_mut`.
+pub struct IterMut<'a, T> {
+    pub(super) head: Link<T>,
+    pub(super) tail: Link<T>,
+    pub(super) remaining: usize,
+    pub(super) marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.head.map(|node| {
+            // SAFETY: the list is mutably borrowed for `'a` and every node is yielded at most
+            // once, so the returned references never alias.
+            let node = unsafe { &mut *node.as_ptr() };
+            self.head = node.next;
+            self.remaining -= 1;
+            &mut node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.tail.map(|node| {
+            // SAFETY: see `next`.
+            let node = unsafe { &mut *node.as_ptr() };
+            self.tail = node.prev;
+            self.remaining -= 1;
+            &mut node.data
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// An iterator that moves the values out of a `DoublyLinkedList`.
+///
+/// Created by the `IntoIterator` implementation of `DoublyLinkedList`.
+pub struct IntoIter<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size, Some(self.list.size))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
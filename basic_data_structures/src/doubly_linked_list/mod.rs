@@ -0,0 +1,246 @@
+use std::{fmt::Display, marker::PhantomData};
+
+use crate::{queue::Queue, stack::Stack};
+
+mod node;
+use node::{Link, Node};
+
+pub mod iter;
+use iter::{Iter, IterMut};
+
+/// A doubly linked list that stores values of type `T`.
+///
+/// Unlike `LinkedList`, it keeps a pointer to both its head and its tail, so pushing and
+/// popping at either end is O(1). That makes it a good backing store for `Queue` and `Stack`.
+///
+/// # Type parameters
+///
+/// * `T`: The type of values that the linked list stores.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::doubly_linked_list::DoublyLinkedList;
+///
+/// let mut list = DoublyLinkedList::new();
+/// list.push_back(2);
+/// list.push_front(1);
+/// assert_eq!(list.pop_back(), Some(2));
+/// assert_eq!(list.size(), 1);
+/// ```
+pub struct DoublyLinkedList<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    size: usize,
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+// The list owns its nodes just like a `Box` would, so it can cross threads whenever `T` can.
+unsafe impl<T: Send> Send for DoublyLinkedList<T> {}
+unsafe impl<T: Sync> Sync for DoublyLinkedList<T> {}
+
+impl<T> DoublyLinkedList<T> {
+    /// Creates a new, empty doubly linked list.
+    pub fn new() -> DoublyLinkedList<T> {
+        DoublyLinkedList {
+            head: None,
+            tail: None,
+            size: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Adds `value` before the current head of the list.
+    pub fn push_front(&mut self, value: T) {
+        let node = Node::new_leaked(value);
+        // SAFETY: `node` was just allocated and every pointer stored in the list points to a
+        // live node owned by it.
+        unsafe {
+            (*node.as_ptr()).next = self.head;
+            match self.head {
+                Some(head) => (*head.as_ptr()).prev = Some(node),
+                None => self.tail = Some(node),
+            }
+        }
+        self.head = Some(node);
+        self.size += 1;
+    }
+
+    /// Adds `value` after the current tail of the list.
+    pub fn push_back(&mut self, value: T) {
+        let node = Node::new_leaked(value);
+        // SAFETY: see `push_front`.
+        unsafe {
+            (*node.as_ptr()).prev = self.tail;
+            match self.tail {
+                Some(tail) => (*tail.as_ptr()).next = Some(node),
+                None => self.head = Some(node),
+            }
+        }
+        self.tail = Some(node);
+        self.size += 1;
+    }
+
+    /// Removes and returns the first value of the list, or `None` if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|head| {
+            // SAFETY: the head node is owned by the list; once it is unlinked nothing else
+            // points to it, so it can be turned back into a `Box`.
+            let node = unsafe { Box::from_raw(head.as_ptr()) };
+            self.head = node.next;
+            match self.head {
+                Some(new_head) => unsafe { (*new_head.as_ptr()).prev = None },
+                None => self.tail = None,
+            }
+            self.size -= 1;
+            node.data
+        })
+    }
+
+    /// Removes and returns the last value of the list, or `None` if it is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|tail| {
+            // SAFETY: see `pop_front`.
+            let node = unsafe { Box::from_raw(tail.as_ptr()) };
+            self.tail = node.prev;
+            match self.tail {
+                Some(new_tail) => unsafe { (*new_tail.as_ptr()).next = None },
+                None => self.head = None,
+            }
+            self.size -= 1;
+            node.data
+        })
+    }
+
+    /// Returns a reference to the first value of the list, or `None` if it is empty.
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: the node is alive for as long as the list is borrowed.
+        self.head.map(|head| unsafe { &(*head.as_ptr()).data })
+    }
+
+    /// Returns a mutable reference to the first value of the list, or `None` if it is empty.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: the list is mutably borrowed, so this is the only reference to the node.
+        self.head.map(|head| unsafe { &mut (*head.as_ptr()).data })
+    }
+
+    /// Returns a reference to the last value of the list, or `None` if it is empty.
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: see `front`.
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).data })
+    }
+
+    /// Returns a mutable reference to the last value of the list, or `None` if it is empty.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: see `front_mut`.
+        self.tail.map(|tail| unsafe { &mut (*tail.as_ptr()).data })
+    }
+
+    /// Returns an iterator over references to the values of the list, from head to tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            remaining: self.size,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the values of the list, from head to tail.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            remaining: self.size,
+            marker: PhantomData,
+        }
+    }
+
+    /// Removes every value of the list.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Checks whether the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns the number of nodes in the list, as an unsigned 32-bit integer.
+    pub fn size(&self) -> u32 {
+        self.size as u32
+    }
+}
+
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for DoublyLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: Display> Display for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut text = String::new();
+        for value in self.iter() {
+            text = format!("{} <-> {}", text, value);
+        }
+
+        text.fmt(f)
+    }
+}
+
+impl<T> Queue<T> for DoublyLinkedList<T> {
+    fn append(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn size(&self) -> u32 {
+        self.size as u32
+    }
+}
+
+impl<T> Stack<T> for DoublyLinkedList<T> {
+    fn prepend(&mut self, value: T) {
+        self.push_front(value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn size(&self) -> u32 {
+        self.size as u32
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.front()
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.front_mut()
+    }
+
+    fn is_empty(&self) -> bool {
+        DoublyLinkedList::is_empty(self)
+    }
+
+    fn clear(&mut self) {
+        DoublyLinkedList::clear(self);
+    }
+}
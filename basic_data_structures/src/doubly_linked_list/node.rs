@@ -0,0 +1,18 @@
+use std::ptr::NonNull;
+
+pub type Link<T> = Option<NonNull<Node<T>>>;
+
+pub struct Node<T> {
+    pub data: T,
+    pub prev: Link<T>,
+    pub next: Link<T>,
+}
+
+impl<T> Node<T> {
+    /// Allocates a new, unlinked node on the heap and leaks it as a raw pointer. The list that
+    /// links it is responsible for turning it back into a `Box` when the node is removed.
+    pub fn new_leaked(value: T) -> NonNull<Node<T>> {
+        let node = Box::new(Node { data: value, prev: None, next: None });
+        NonNull::from(Box::leak(node))
+    }
+}
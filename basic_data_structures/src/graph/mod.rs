@@ -0,0 +1,301 @@
+use std::{fmt::Display, iter::FusedIterator, slice};
+
+use crate::{
+    queue::{ArrayQueue, Queue},
+    stack::{ArrayStack, Stack},
+};
+
+pub mod traversal;
+pub use traversal::{Bfs, Dfs, ParentTree};
+pub mod shortest_path;
+pub use shortest_path::{NegativeCycle, ShortestPaths, Weight};
+
+/// Whether the edges of a `Graph` have a direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GraphKind {
+    /// An edge from `a` to `b` only lets you go from `a` to `b`.
+    Directed,
+    /// An edge between `a` and `b` can be followed both ways.
+    Undirected,
+}
+
+/// An edge of a `Graph`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Edge<E> {
+    pub from: usize,
+    pub to: usize,
+    pub weight: E,
+}
+
+/// A graph stored as adjacency lists.
+///
+/// Nodes and edges are identified by the index `add_node` and `add_edge` return, starting from
+/// `0`. Nothing is ever removed, so indices stay valid for the whole life of the graph. Each
+/// node has the list of edges leaving it; in an undirected graph an edge is in the lists of both
+/// of its ends.
+///
+/// # Type parameters
+///
+/// * `N`: The type of the data stored in each node.
+/// * `E`: The type of the data stored in each edge, such as its weight.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::graph::Graph;
+///
+/// let mut graph = Graph::new_undirected();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.add_edge(a, b, ());
+/// graph.add_edge(b, c, ());
+///
+/// let order: Vec<_> = graph.bfs(c).map(|node| graph[node]).collect();
+/// assert_eq!(order, vec!["c", "b", "a"]);
+/// ```
+#[derive(Clone)]
+pub struct Graph<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<Edge<E>>,
+    /// For each node, the `(neighbor, edge)` index pairs of the edges leaving it.
+    adjacency: Vec<Vec<(usize, usize)>>,
+    kind: GraphKind,
+}
+
+impl<N, E> Graph<N, E> {
+    /// Creates a new, empty graph of the given kind.
+    pub fn new(kind: GraphKind) -> Self {
+        Graph { nodes: Vec::new(), edges: Vec::new(), adjacency: Vec::new(), kind }
+    }
+
+    /// Creates a new, empty directed graph.
+    pub fn new_directed() -> Self {
+        Self::new(GraphKind::Directed)
+    }
+
+    /// Creates a new, empty undirected graph.
+    pub fn new_undirected() -> Self {
+        Self::new(GraphKind::Undirected)
+    }
+
+    /// Returns the kind of the graph.
+    pub fn kind(&self) -> GraphKind {
+        self.kind
+    }
+
+    /// Checks whether the edges of the graph have a direction.
+    pub fn is_directed(&self) -> bool {
+        self.kind == GraphKind::Directed
+    }
+
+    /// Adds a node holding `data` to the graph.
+    ///
+    /// # Returns
+    ///
+    /// The index of the new node.
+    pub fn add_node(&mut self, data: N) -> usize {
+        self.nodes.push(data);
+        self.adjacency.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    /// Adds an edge from `from` to `to` holding `weight`. Parallel edges and loops are allowed.
+    ///
+    /// # Returns
+    ///
+    /// The index of the new edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` is not a node of the graph.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: E) -> usize {
+        assert!(from < self.nodes.len(), "node {} is not in the graph", from);
+        assert!(to < self.nodes.len(), "node {} is not in the graph", to);
+        let index = self.edges.len();
+        self.edges.push(Edge { from, to, weight });
+        self.adjacency[from].push((to, index));
+        if self.kind == GraphKind::Undirected && from != to {
+            self.adjacency[to].push((from, index));
+        }
+        index
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns a reference to the data of `node`.
+    pub fn node(&self, node: usize) -> Option<&N> {
+        self.nodes.get(node)
+    }
+
+    /// Returns a mutable reference to the data of `node`.
+    pub fn node_mut(&mut self, node: usize) -> Option<&mut N> {
+        self.nodes.get_mut(node)
+    }
+
+    /// Returns a reference to the edge with the given index.
+    pub fn edge(&self, edge: usize) -> Option<&Edge<E>> {
+        self.edges.get(edge)
+    }
+
+    /// Returns every edge of the graph, in the order they were added.
+    pub fn edges(&self) -> &[Edge<E>] {
+        &self.edges
+    }
+
+    /// Returns an iterator over the nodes that can be reached from `node` through a single edge,
+    /// in the order the edges were added. A node linked by several edges comes up once per edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is not a node of the graph.
+    pub fn neighbors(&self, node: usize) -> Neighbors<'_, E> {
+        Neighbors { edges: self.edges_from(node) }
+    }
+
+    /// Returns an iterator over the edges that leave `node`, as `(neighbor, weight)` pairs, in the
+    /// order they were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is not a node of the graph.
+    pub fn edges_from(&self, node: usize) -> EdgesFrom<'_, E> {
+        EdgesFrom { adjacency: self.adjacency[node].iter(), edges: &self.edges }
+    }
+
+    /// Returns a breadth-first traversal from `start` that keeps its frontier in an
+    /// `ArrayQueue`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is not a node of the graph.
+    pub fn bfs(&self, start: usize) -> Bfs<'_, N, E, ArrayQueue<usize>> {
+        Bfs::new(self, start, ArrayQueue::new())
+    }
+
+    /// Returns a breadth-first traversal from `start` that keeps its frontier in `queue`, which
+    /// should be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is not a node of the graph.
+    pub fn bfs_with<Q: Queue<usize>>(&self, start: usize, queue: Q) -> Bfs<'_, N, E, Q> {
+        Bfs::new(self, start, queue)
+    }
+
+    /// Returns a depth-first traversal from `start` that keeps its frontier in an `ArrayStack`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is not a node of the graph.
+    pub fn dfs(&self, start: usize) -> Dfs<'_, N, E, ArrayStack<usize>> {
+        Dfs::new(self, start, ArrayStack::new())
+    }
+
+    /// Returns a depth-first traversal from `start` that keeps its frontier in `stack`, which
+    /// should be empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is not a node of the graph.
+    pub fn dfs_with<S: Stack<usize>>(&self, start: usize, stack: S) -> Dfs<'_, N, E, S> {
+        Dfs::new(self, start, stack)
+    }
+}
+
+impl<N, E> std::ops::Index<usize> for Graph<N, E> {
+    type Output = N;
+
+    fn index(&self, node: usize) -> &N {
+        &self.nodes[node]
+    }
+}
+
+impl<N, E> std::ops::IndexMut<usize> for Graph<N, E> {
+    fn index_mut(&mut self, node: usize) -> &mut N {
+        &mut self.nodes[node]
+    }
+}
+
+impl<N: Display, E> Graph<N, E> {
+    /// Prints each node followed by its neighbors.
+    pub fn print(&self) {
+        let arrow = if self.is_directed() { "->" } else { "--" };
+        for (node, data) in self.nodes.iter().enumerate() {
+            let neighbors: Vec<String> =
+                self.neighbors(node).map(|neighbor| self.nodes[neighbor].to_string()).collect();
+            println!("{} {} [{}]", data, arrow, neighbors.join(", "));
+        }
+    }
+}
+
+/// An iterator over the edges leaving a node of a `Graph`, as `(neighbor, weight)` pairs.
+///
+/// Created by `Graph::edges_from`.
+pub struct EdgesFrom<'a, E> {
+    adjacency: slice::Iter<'a, (usize, usize)>,
+    edges: &'a [Edge<E>],
+}
+
+impl<'a, E> Iterator for EdgesFrom<'a, E> {
+    type Item = (usize, &'a E);
+
+    fn next(&mut self) -> Option<(usize, &'a E)> {
+        let &(neighbor, edge) = self.adjacency.next()?;
+        Some((neighbor, &self.edges[edge].weight))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.adjacency.size_hint()
+    }
+}
+
+impl<E> DoubleEndedIterator for EdgesFrom<'_, E> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let &(neighbor, edge) = self.adjacency.next_back()?;
+        Some((neighbor, &self.edges[edge].weight))
+    }
+}
+
+impl<E> ExactSizeIterator for EdgesFrom<'_, E> {}
+
+impl<E> FusedIterator for EdgesFrom<'_, E> {}
+
+/// An iterator over the neighbors of a node of a `Graph`.
+///
+/// Created by `Graph::neighbors`.
+pub struct Neighbors<'a, E> {
+    edges: EdgesFrom<'a, E>,
+}
+
+impl<E> Iterator for Neighbors<'_, E> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.edges.next().map(|(neighbor, _)| neighbor)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+impl<E> DoubleEndedIterator for Neighbors<'_, E> {
+    fn next_back(&mut self) -> Option<usize> {
+        self.edges.next_back().map(|(neighbor, _)| neighbor)
+    }
+}
+
+impl<E> ExactSizeIterator for Neighbors<'_, E> {}
+
+impl<E> FusedIterator for Neighbors<'_, E> {}
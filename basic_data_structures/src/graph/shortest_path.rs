@@ -0,0 +1,342 @@
+use std::{cmp::Ordering, error::Error, fmt, ops::Add};
+
+use super::{traversal::ParentTree, Graph};
+use crate::priority_queue::PriorityQueue;
+
+/// A type that can be used as the weight of an edge when looking for shortest paths.
+///
+/// It is implemented for every primitive integer and float type. Float weights must not be
+/// `NaN`: they can't be compared, so the searches panic when they meet one.
+pub trait Weight: Copy + PartialOrd + Add<Output = Self> {
+    /// The length of an empty path.
+    fn zero() -> Self;
+}
+
+macro_rules! weight_impls {
+    ($zero:expr => $($weight:ty),*) => {
+        $(
+            impl Weight for $weight {
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+        )*
+    };
+}
+
+weight_impls!(0 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+weight_impls!(0.0 => f32, f64);
+
+/// A node waiting in the priority queue with its score and the length of the path it was
+/// reached by. It is ordered by its score only, so that weights which are just `PartialOrd`,
+/// like floats, can be used.
+struct Scored<W>(W, W, usize);
+
+impl<W: PartialOrd> PartialEq for Scored<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: PartialOrd> Eq for Scored<W> {}
+
+impl<W: PartialOrd> PartialOrd for Scored<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: PartialOrd> Ord for Scored<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).expect("A* heuristics must not return NaN")
+    }
+}
+
+/// The shortest paths from one node to every node of a `Graph`.
+///
+/// Created by `Graph::dijkstra` and `Graph::bellman_ford`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortestPaths<W> {
+    distances: Vec<Option<W>>,
+    tree: ParentTree,
+}
+
+impl<W: Weight> ShortestPaths<W> {
+    fn new(source: usize, distances: Vec<Option<W>>, parents: Vec<Option<usize>>) -> Self {
+        let reached = distances.iter().map(Option::is_some).collect();
+        ShortestPaths { distances, tree: ParentTree::new(source, parents, reached) }
+    }
+
+    /// Returns the node the paths start from.
+    pub fn source(&self) -> usize {
+        self.tree.root()
+    }
+
+    /// Returns the length of the shortest path to `node`, or `None` if it can't be reached.
+    pub fn distance(&self, node: usize) -> Option<W> {
+        self.distances.get(node).copied().flatten()
+    }
+
+    /// Returns the length of the shortest path to every node of the graph, indexed by node.
+    pub fn distances(&self) -> &[Option<W>] {
+        &self.distances
+    }
+
+    /// Returns the nodes on the shortest path from the source to `node`, both included, or
+    /// `None` if `node` can't be reached.
+    pub fn path_to(&self, node: usize) -> Option<Vec<usize>> {
+        self.tree.path_to(node)
+    }
+
+    /// Returns the tree formed by the shortest paths: the parent of each node is the one before
+    /// it on its shortest path.
+    pub fn tree(&self) -> &ParentTree {
+        &self.tree
+    }
+}
+
+/// The error `Graph::bellman_ford` returns when a cycle whose weights add up to less than zero
+/// can be reached from the source, which makes paths through it as short as you want.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NegativeCycle {
+    cycle: Vec<usize>,
+}
+
+impl NegativeCycle {
+    /// Returns the nodes of the cycle, in the order its edges go through them. The edge from the
+    /// last node back to the first one closes it.
+    pub fn cycle(&self) -> &[usize] {
+        &self.cycle
+    }
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes: Vec<String> = self.cycle.iter().map(|node| node.to_string()).collect();
+        write!(f, "negative cycle through nodes {}", nodes.join(" -> "))
+    }
+}
+
+impl Error for NegativeCycle {}
+
+/// Panics if `weight` is `NaN`, the only value a `Weight` can't be compared with.
+fn check_weight<W: Weight>(weight: W) {
+    assert!(weight.partial_cmp(&weight).is_some(), "shortest paths can't be found with NaN weights");
+}
+
+impl<N, E: Weight> Graph<N, E> {
+    /// Finds the shortest paths from `source` to every node with Dijkstra's algorithm, in
+    /// O((V + E) log V).
+    ///
+    /// Weights must not be negative; use `bellman_ford` if some are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` is not a node of the graph, or if the search meets a negative or
+    /// `NaN` weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use basic_data_structures::graph::Graph;
+    ///
+    /// let mut graph = Graph::new_directed();
+    /// for _ in 0..3 {
+    ///     graph.add_node(());
+    /// }
+    /// graph.add_edge(0, 1, 4);
+    /// graph.add_edge(0, 2, 1);
+    /// graph.add_edge(2, 1, 2);
+    ///
+    /// let paths = graph.dijkstra(0);
+    /// assert_eq!(paths.distance(1), Some(3));
+    /// assert_eq!(paths.path_to(1), Some(vec![0, 2, 1]));
+    /// ```
+    pub fn dijkstra(&self, source: usize) -> ShortestPaths<E> {
+        let (distances, parents) = self.best_first(source, None, |_| E::zero());
+        ShortestPaths::new(source, distances, parents)
+    }
+
+    /// Finds a shortest path from `source` to `goal` with the A* algorithm.
+    ///
+    /// `heuristic` estimates the length of the shortest path from a node to `goal`. The search
+    /// explores the nodes in order of distance from `source` plus estimate, so a good heuristic
+    /// lets it skip most of the graph. It must never overestimate, otherwise the path found may
+    /// not be the shortest; a heuristic that always returns zero turns A* into Dijkstra's
+    /// algorithm. A node is explored again whenever a shorter path to it is found, so the
+    /// heuristic doesn't have to be consistent, but with a consistent one, where
+    /// `h(u) <= w(u, v) + h(v)` for every edge, no node is explored twice. Weights must not be
+    /// negative.
+    ///
+    /// # Returns
+    ///
+    /// The length of the path and its nodes, from `source` to `goal`, or `None` if `goal` can't
+    /// be reached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` or `goal` is not a node of the graph, if the search meets a negative
+    /// or `NaN` weight, or if `heuristic` returns `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use basic_data_structures::graph::Graph;
+    ///
+    /// // A 1D corridor: the heuristic is the distance left to walk.
+    /// let mut graph = Graph::new_undirected();
+    /// for x in 0..10 {
+    ///     graph.add_node(x);
+    /// }
+    /// for x in 0..9 {
+    ///     graph.add_edge(x, x + 1, 1);
+    /// }
+    ///
+    /// let (length, path) = graph.astar(2, 7, |node| 7usize.abs_diff(graph[node])).unwrap();
+    /// assert_eq!(length, 5);
+    /// assert_eq!(path, vec![2, 3, 4, 5, 6, 7]);
+    /// ```
+    pub fn astar<H>(&self, source: usize, goal: usize, heuristic: H) -> Option<(E, Vec<usize>)>
+    where
+        H: FnMut(usize) -> E,
+    {
+        assert!(goal < self.node_count(), "node {} is not in the graph", goal);
+        let (distances, parents) = self.best_first(source, Some(goal), heuristic);
+        let length = distances[goal]?;
+        let path = ParentTree::new(source, parents, distances.iter().map(Option::is_some).collect())
+            .path_to(goal)?;
+        Some((length, path))
+    }
+
+    /// Explores the graph from `source` in order of distance plus `heuristic`, until `goal` is
+    /// reached by a path no other can beat or every reachable node has been explored.
+    ///
+    /// # Returns
+    ///
+    /// The best distance found to each node and the node it was reached from.
+    fn best_first<H>(
+        &self,
+        source: usize,
+        goal: Option<usize>,
+        mut heuristic: H,
+    ) -> (Vec<Option<E>>, Vec<Option<usize>>)
+    where
+        H: FnMut(usize) -> E,
+    {
+        assert!(source < self.node_count(), "node {} is not in the graph", source);
+        let mut distances = vec![None; self.node_count()];
+        let mut parents = vec![None; self.node_count()];
+        let mut queue = PriorityQueue::new_min();
+
+        distances[source] = Some(E::zero());
+        queue.push(Scored(heuristic(source), E::zero(), source));
+        while let Some(Scored(_, distance, node)) = queue.pop() {
+            // A shorter path to the node was found after this one was queued.
+            if distances[node].is_some_and(|best| best < distance) {
+                continue;
+            }
+            if Some(node) == goal {
+                break;
+            }
+            for (neighbor, &weight) in self.edges_from(node) {
+                check_weight(weight);
+                assert!(weight >= E::zero(), "best-first searches can't use negative weights");
+                let candidate = distance + weight;
+                if distances[neighbor].is_none_or(|best| candidate < best) {
+                    distances[neighbor] = Some(candidate);
+                    parents[neighbor] = Some(node);
+                    queue.push(Scored(candidate + heuristic(neighbor), candidate, neighbor));
+                }
+            }
+        }
+        (distances, parents)
+    }
+
+    /// Finds the shortest paths from `source` to every node with the Bellman-Ford algorithm, in
+    /// O(V * E).
+    ///
+    /// Unlike `dijkstra`, it allows negative weights. In an undirected graph, an edge with a
+    /// negative weight is a negative cycle on its own, since it can be walked back and forth.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NegativeCycle` if a cycle with a negative total weight can be reached from
+    /// `source`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` is not a node of the graph, or if the search meets a `NaN` weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use basic_data_structures::graph::Graph;
+    ///
+    /// let mut graph = Graph::new_directed();
+    /// for _ in 0..3 {
+    ///     graph.add_node(());
+    /// }
+    /// graph.add_edge(0, 1, 4);
+    /// graph.add_edge(0, 2, 5);
+    /// graph.add_edge(2, 1, -3);
+    /// assert_eq!(graph.bellman_ford(0).unwrap().distance(1), Some(2));
+    ///
+    /// graph.add_edge(1, 2, 1);
+    /// assert_eq!(graph.bellman_ford(0).unwrap_err().cycle(), &[1, 2]);
+    /// ```
+    pub fn bellman_ford(&self, source: usize) -> Result<ShortestPaths<E>, NegativeCycle> {
+        assert!(source < self.node_count(), "node {} is not in the graph", source);
+        let mut distances = vec![None; self.node_count()];
+        let mut parents = vec![None; self.node_count()];
+        distances[source] = Some(E::zero());
+
+        // Shortest paths have at most V - 1 edges, so V - 1 rounds settle every distance. A
+        // change in one more round means there is a negative cycle.
+        for _ in 0..self.node_count() {
+            let mut last_relaxed = None;
+            for node in 0..self.node_count() {
+                let distance = match distances[node] {
+                    Some(distance) => distance,
+                    None => continue,
+                };
+                for (neighbor, &weight) in self.edges_from(node) {
+                    check_weight(weight);
+                    let candidate = distance + weight;
+                    if distances[neighbor].is_none_or(|best| candidate < best) {
+                        distances[neighbor] = Some(candidate);
+                        parents[neighbor] = Some(node);
+                        last_relaxed = Some(neighbor);
+                    }
+                }
+            }
+            match last_relaxed {
+                None => return Ok(ShortestPaths::new(source, distances, parents)),
+                Some(node) => {
+                    if let Some(cycle) = Self::find_cycle(&parents, node) {
+                        return Err(NegativeCycle { cycle });
+                    }
+                }
+            }
+        }
+        unreachable!("a negative cycle is found at the latest in the last round")
+    }
+
+    /// Looks for a cycle among the parents of `node`. Any cycle in the parent pointers has a
+    /// negative weight.
+    fn find_cycle(parents: &[Option<usize>], node: usize) -> Option<Vec<usize>> {
+        // A path through more nodes than the graph has must have looped, so walking up that
+        // many parents ends on the cycle.
+        let mut current = node;
+        for _ in 0..parents.len() {
+            current = parents[current]?;
+        }
+        let mut cycle = vec![current];
+        let mut previous = parents[current]?;
+        while previous != current {
+            cycle.push(previous);
+            previous = parents[previous]?;
+        }
+        cycle.reverse();
+        Some(cycle)
+    }
+}
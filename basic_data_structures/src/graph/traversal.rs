@@ -0,0 +1,212 @@
+use std::iter::FusedIterator;
+
+use super::Graph;
+use crate::{queue::Queue, stack::Stack};
+
+/// The tree a traversal builds: for each node it reached, the node it was reached from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParentTree {
+    root: usize,
+    /// `None` for the root and for the nodes that were not reached.
+    parents: Vec<Option<usize>>,
+    reached: Vec<bool>,
+}
+
+impl ParentTree {
+    pub(super) fn new(root: usize, parents: Vec<Option<usize>>, reached: Vec<bool>) -> Self {
+        ParentTree { root, parents, reached }
+    }
+
+    /// Returns the node the traversal started at.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// Checks whether the traversal reached `node`.
+    pub fn contains(&self, node: usize) -> bool {
+        self.reached.get(node).copied().unwrap_or(false)
+    }
+
+    /// Returns the node `node` was reached from. It is `None` for the root and for the nodes
+    /// that were not reached.
+    pub fn parent(&self, node: usize) -> Option<usize> {
+        self.parents.get(node).copied().flatten()
+    }
+
+    /// Returns the parent of every node of the graph, indexed by node.
+    pub fn parents(&self) -> &[Option<usize>] {
+        &self.parents
+    }
+
+    /// Returns the nodes on the branch of the tree from the root to `node`, both included, or
+    /// `None` if `node` was not reached.
+    ///
+    /// After a breadth-first traversal, it is a path with the fewest possible edges.
+    pub fn path_to(&self, node: usize) -> Option<Vec<usize>> {
+        if !self.contains(node) {
+            return None;
+        }
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(parent) = self.parent(current) {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// The state both traversals share.
+struct Visit<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    root: usize,
+    parents: Vec<Option<usize>>,
+    reached: Vec<bool>,
+}
+
+impl<'a, N, E> Visit<'a, N, E> {
+    fn new(graph: &'a Graph<N, E>, root: usize) -> Self {
+        assert!(root < graph.node_count(), "node {} is not in the graph", root);
+        let mut reached = vec![false; graph.node_count()];
+        reached[root] = true;
+        Visit { graph, root, parents: vec![None; graph.node_count()], reached }
+    }
+
+    fn into_tree(self) -> ParentTree {
+        ParentTree::new(self.root, self.parents, self.reached)
+    }
+}
+
+/// A breadth-first traversal of a `Graph`, which yields the nodes reachable from its start in
+/// order of distance, counted in edges.
+///
+/// The frontier is kept in any `Queue<usize>`, such as an `ArrayQueue` or a `LinkedList`.
+/// While it runs, the traversal records the node each node was discovered from; once it is
+/// done, `into_tree` returns that as a `ParentTree`.
+///
+/// Created by `Graph::bfs` and `Graph::bfs_with`.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::{graph::Graph, linked_list::LinkedList};
+///
+/// let mut graph = Graph::new_directed();
+/// for _ in 0..4 {
+///     graph.add_node(());
+/// }
+/// graph.add_edge(0, 1, ());
+/// graph.add_edge(0, 2, ());
+/// graph.add_edge(2, 3, ());
+///
+/// let mut bfs = graph.bfs_with(0, LinkedList::new());
+/// let order: Vec<_> = bfs.by_ref().collect();
+/// assert_eq!(order, vec![0, 1, 2, 3]);
+/// assert_eq!(bfs.into_tree().path_to(3), Some(vec![0, 2, 3]));
+/// ```
+pub struct Bfs<'a, N, E, Q> {
+    visit: Visit<'a, N, E>,
+    queue: Q,
+}
+
+impl<'a, N, E, Q: Queue<usize>> Bfs<'a, N, E, Q> {
+    pub(super) fn new(graph: &'a Graph<N, E>, start: usize, mut queue: Q) -> Self {
+        let visit = Visit::new(graph, start);
+        queue.append(start);
+        Bfs { visit, queue }
+    }
+
+    /// Returns the parent of every node discovered so far, indexed by node.
+    pub fn parents(&self) -> &[Option<usize>] {
+        &self.visit.parents
+    }
+
+    /// Runs the rest of the traversal and returns the tree it built.
+    pub fn into_tree(mut self) -> ParentTree {
+        self.by_ref().for_each(drop);
+        self.visit.into_tree()
+    }
+}
+
+impl<N, E, Q: Queue<usize>> Iterator for Bfs<'_, N, E, Q> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let node = self.queue.pop()?;
+        let visit = &mut self.visit;
+        for neighbor in visit.graph.neighbors(node) {
+            if !visit.reached[neighbor] {
+                visit.reached[neighbor] = true;
+                visit.parents[neighbor] = Some(node);
+                self.queue.append(neighbor);
+            }
+        }
+        Some(node)
+    }
+}
+
+impl<N, E, Q: Queue<usize>> FusedIterator for Bfs<'_, N, E, Q> {}
+
+/// A depth-first traversal of a `Graph`, which follows each branch as deep as it goes before
+/// backtracking. Neighbors are explored in the order their edges were added.
+///
+/// The frontier is kept in any `Stack<usize>`, such as an `ArrayStack` or a `LinkedList`.
+/// While it runs, the traversal records the node each node was visited from; once it is done,
+/// `into_tree` returns that as a `ParentTree`.
+///
+/// Created by `Graph::dfs` and `Graph::dfs_with`.
+pub struct Dfs<'a, N, E, S> {
+    visit: Visit<'a, N, E>,
+    stack: S,
+    /// Whether each node has been yielded. A node can be on the stack several times, once for
+    /// each visited neighbor that pushed it, but it is only yielded the first time it is popped.
+    visited: Vec<bool>,
+}
+
+impl<'a, N, E, S: Stack<usize>> Dfs<'a, N, E, S> {
+    pub(super) fn new(graph: &'a Graph<N, E>, start: usize, mut stack: S) -> Self {
+        let visit = Visit::new(graph, start);
+        stack.prepend(start);
+        Dfs { visit, stack, visited: vec![false; graph.node_count()] }
+    }
+
+    /// Returns the parent of every node visited so far, indexed by node. Nodes that are only on
+    /// the stack may still change parent.
+    pub fn parents(&self) -> &[Option<usize>] {
+        &self.visit.parents
+    }
+
+    /// Runs the rest of the traversal and returns the tree it built.
+    pub fn into_tree(mut self) -> ParentTree {
+        self.by_ref().for_each(drop);
+        self.visit.into_tree()
+    }
+}
+
+impl<N, E, S: Stack<usize>> Iterator for Dfs<'_, N, E, S> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let node = loop {
+            let node = self.stack.pop()?;
+            if !self.visited[node] {
+                break node;
+            }
+        };
+        self.visited[node] = true;
+        let visit = &mut self.visit;
+        // Pushing in reverse makes the first neighbor the first one popped. The last node to
+        // push a neighbor is the one it will be visited from, so that is its parent.
+        for neighbor in visit.graph.neighbors(node).rev() {
+            if !self.visited[neighbor] {
+                visit.reached[neighbor] = true;
+                visit.parents[neighbor] = Some(node);
+                self.stack.prepend(neighbor);
+            }
+        }
+        Some(node)
+    }
+}
+
+impl<N, E, S: Stack<usize>> FusedIterator for Dfs<'_, N, E, S> {}
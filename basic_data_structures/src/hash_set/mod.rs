@@ -0,0 +1,314 @@
+use std::{
+    borrow::Borrow,
+    fmt::{self, Debug, Display},
+    hash::{BuildHasher, Hash},
+    iter::{Chain, FusedIterator},
+};
+
+use crate::hash_table::{iter::Keys, HashTable, SipHash};
+
+/// A set of unique values, stored as the keys of a `HashTable` whose values are `()`.
+///
+/// Besides the usual `insert`, `contains` and `remove`, it offers set algebra. `union`,
+/// `intersection`, `difference` and `symmetric_difference` return lazy iterators that borrow
+/// both sets, so nothing is allocated until the result is collected.
+///
+/// # Type parameters
+///
+/// * `T`: The type of the values, which must implement `Hash` and `Eq`.
+/// * `S`: The `BuildHasher` of the underlying table. Defaults to `SipHash`.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::hash_set::HashSet;
+///
+/// let odd: HashSet<u32> = [1, 3, 5].into_iter().collect();
+/// let small: HashSet<u32> = [1, 2, 3].into_iter().collect();
+///
+/// let mut both: Vec<_> = odd.intersection(&small).copied().collect();
+/// both.sort();
+/// assert_eq!(both, vec![1, 3]);
+/// assert!(!odd.is_subset(&small));
+/// ```
+pub struct HashSet<T, S = SipHash> {
+    table: HashTable<T, (), S>,
+}
+
+impl<T: Hash + Eq> HashSet<T, SipHash> {
+    /// Creates a new, empty set.
+    pub fn new() -> Self {
+        HashSet { table: HashTable::new() }
+    }
+
+    /// Creates a new, empty set with room for `capacity` values before it has to grow.
+    pub fn with_capacity(capacity: usize) -> Self {
+        HashSet { table: HashTable::with_capacity(capacity) }
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> HashSet<T, S> {
+    /// Creates a new, empty set that uses `hash_builder` to hash its values.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        HashSet { table: HashTable::with_hasher(hash_builder) }
+    }
+
+    /// Creates a new, empty set with room for `capacity` values before it has to grow, which
+    /// uses `hash_builder` to hash its values.
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        HashSet { table: HashTable::with_capacity_and_hasher(capacity, hash_builder) }
+    }
+
+    /// Adds `value` to the set.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value was not in the set yet, `false` if it already was. In that case the
+    /// set is left untouched.
+    pub fn insert(&mut self, value: T) -> bool {
+        if self.table.contains_key(&value) {
+            return false;
+        }
+        self.table.insert(value, ());
+        true
+    }
+
+    /// Returns `true` if the set contains `value`.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.table.contains_key(value)
+    }
+
+    /// Removes `value` from the set.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value was in the set.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.table.remove(value).is_some()
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Checks whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Removes every value from the set.
+    pub fn clear(&mut self) {
+        self.table.clear();
+    }
+
+    /// Returns a reference to the set's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        self.table.hasher()
+    }
+
+    /// Returns an iterator over the values of the set, in no particular order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { keys: self.table.keys() }
+    }
+
+    /// Returns a lazy iterator over the values that are in `self`, `other` or both, each one
+    /// visited once.
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T, S> {
+        Union { iter: self.iter().chain(other.difference(self)) }
+    }
+
+    /// Returns a lazy iterator over the values that are both in `self` and in `other`.
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, T, S> {
+        // Walking the smaller set means fewer lookups into the bigger one.
+        let (smaller, bigger) = if self.len() <= other.len() { (self, other) } else { (other, self) };
+        Intersection { iter: smaller.iter(), other: bigger }
+    }
+
+    /// Returns a lazy iterator over the values that are in `self` but not in `other`.
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T, S> {
+        Difference { iter: self.iter(), other }
+    }
+
+    /// Returns a lazy iterator over the values that are in `self` or in `other`, but not in
+    /// both.
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, T, S> {
+        SymmetricDifference { iter: self.difference(other).chain(other.difference(self)) }
+    }
+
+    /// Returns `true` if every value of `self` is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.len() <= other.len() && self.iter().all(|value| other.contains(value))
+    }
+
+    /// Returns `true` if every value of `other` is also in `self`.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if `self` and `other` have no value in common.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).next().is_none()
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher + Default> Default for HashSet<T, S> {
+    fn default() -> Self {
+        HashSet { table: HashTable::default() }
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> PartialEq for HashSet<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Eq for HashSet<T, S> {}
+
+impl<T: Debug, S> Debug for HashSet<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.table.keys()).finish()
+    }
+}
+
+impl<T: Display, S> HashSet<T, S> {
+    pub fn print(&self) {
+        let values: Vec<String> = self.table.keys().map(|value| value.to_string()).collect();
+        println!("{{{}}}", values.join(", "));
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher + Default> FromIterator<T> for HashSet<T, S> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::default();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Extend<T> for HashSet<T, S> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<'a, T: Hash + Eq, S: BuildHasher> IntoIterator for &'a HashSet<T, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a `HashSet`.
+///
+/// Created by `HashSet::iter`.
+pub struct Iter<'a, T> {
+    keys: Keys<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.keys.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { keys: self.keys.clone() }
+    }
+}
+
+/// A lazy iterator over the values of the union of two `HashSet`s.
+///
+/// Created by `HashSet::union`.
+pub struct Union<'a, T, S> {
+    iter: Chain<Iter<'a, T>, Difference<'a, T, S>>,
+}
+
+impl<'a, T: Hash + Eq, S: BuildHasher> Iterator for Union<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> FusedIterator for Union<'_, T, S> {}
+
+/// A lazy iterator over the values of the intersection of two `HashSet`s.
+///
+/// Created by `HashSet::intersection`.
+pub struct Intersection<'a, T, S> {
+    iter: Iter<'a, T>,
+    other: &'a HashSet<T, S>,
+}
+
+impl<'a, T: Hash + Eq, S: BuildHasher> Iterator for Intersection<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let other = self.other;
+        self.iter.by_ref().find(|value| other.contains(*value))
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> FusedIterator for Intersection<'_, T, S> {}
+
+/// A lazy iterator over the values of the difference of two `HashSet`s.
+///
+/// Created by `HashSet::difference`.
+pub struct Difference<'a, T, S> {
+    iter: Iter<'a, T>,
+    other: &'a HashSet<T, S>,
+}
+
+impl<'a, T: Hash + Eq, S: BuildHasher> Iterator for Difference<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let other = self.other;
+        self.iter.by_ref().find(|value| !other.contains(*value))
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> FusedIterator for Difference<'_, T, S> {}
+
+/// A lazy iterator over the values of the symmetric difference of two `HashSet`s.
+///
+/// Created by `HashSet::symmetric_difference`.
+pub struct SymmetricDifference<'a, T, S> {
+    iter: Chain<Difference<'a, T, S>, Difference<'a, T, S>>,
+}
+
+impl<'a, T: Hash + Eq, S: BuildHasher> Iterator for SymmetricDifference<'a, T, S> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> FusedIterator for SymmetricDifference<'_, T, S> {}
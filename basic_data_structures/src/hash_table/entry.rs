@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+/// A key/value pair stored inside one of the rows of a `HashTable`.
+#[derive(Clone)]
+pub struct Entry<K, V> {
+    pub key: K,
+    pub value: V,
+}
+
+impl<K, V> Entry<K, V> {
+    pub fn new(key: K, value: V) -> Entry<K, V> {
+        Entry { key, value }
+    }
+}
+
+impl<K: Display, V: Display> Display for Entry<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.key, self.value)
+    }
+}
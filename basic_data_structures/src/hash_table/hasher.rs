@@ -0,0 +1,186 @@
+use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+/// Builds `ByteSumHasher`s.
+pub type ByteSum = BuildHasherDefault<ByteSumHasher>;
+
+/// Builds `StringFoldingHasher`s.
+pub type StringFolding = BuildHasherDefault<StringFoldingHasher>;
+
+/// Builds `Fnv1aHasher`s.
+pub type Fnv1a = BuildHasherDefault<Fnv1aHasher>;
+
+/// Adds up every byte written into it.
+///
+/// Very fast but very weak: any two keys made of the same bytes collide.
+#[derive(Default, Clone)]
+pub struct ByteSumHasher(u32);
+
+impl Hasher for ByteSumHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = self.0.wrapping_add(*byte as u32);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0 as u64
+    }
+}
+
+/// Adds up every byte written into it, weighting the bytes that are not a multiple of 4
+/// so similar keys spread across more rows than with `ByteSumHasher`.
+#[derive(Default, Clone)]
+pub struct StringFoldingHasher(u32);
+
+impl Hasher for StringFoldingHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            let mult = if byte % 4 == 0 { 1 } else { 255 };
+            self.0 = self.0.wrapping_add(*byte as u32 * mult);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0 as u64
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// The 64 bit FNV-1a hash: xors every byte into the state and then multiplies it by the FNV prime.
+#[derive(Clone)]
+pub struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Fnv1aHasher(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Builds `SipHasher13`s that share the same pair of keys. This is the default hasher of
+/// `HashTable`.
+///
+/// Tables built with different keys spread the same keys differently, which also makes it
+/// easy to derive several independent hash functions from a single seed.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SipHash {
+    k0: u64,
+    k1: u64,
+}
+
+impl SipHash {
+    /// Creates a builder whose hashers use the keys `(0, 0)`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder whose hashers are seeded with the given keys.
+    pub fn with_keys(k0: u64, k1: u64) -> Self {
+        SipHash { k0, k1 }
+    }
+}
+
+impl BuildHasher for SipHash {
+    type Hasher = SipHasher13;
+
+    fn build_hasher(&self) -> SipHasher13 {
+        SipHasher13::new_with_keys(self.k0, self.k1)
+    }
+}
+
+/// SipHash with 1 compression round and 3 finalization rounds, the variant the standard
+/// library uses for its `HashMap`.
+#[derive(Clone)]
+pub struct SipHasher13 {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+    tail: u64,
+    tail_len: usize,
+    length: usize,
+}
+
+impl SipHasher13 {
+    /// Creates a hasher seeded with the given keys.
+    pub fn new_with_keys(k0: u64, k1: u64) -> Self {
+        SipHasher13 {
+            v0: k0 ^ 0x736f_6d65_7073_6575,
+            v1: k1 ^ 0x646f_7261_6e64_6f6d,
+            v2: k0 ^ 0x6c79_6765_6e65_7261,
+            v3: k1 ^ 0x7465_6462_7974_6573,
+            tail: 0,
+            tail_len: 0,
+            length: 0,
+        }
+    }
+
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13);
+        self.v1 ^= self.v0;
+        self.v0 = self.v0.rotate_left(32);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16);
+        self.v3 ^= self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21);
+        self.v3 ^= self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17);
+        self.v1 ^= self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    fn compress(&mut self, word: u64) {
+        self.v3 ^= word;
+        self.round();
+        self.v0 ^= word;
+    }
+}
+
+impl Default for SipHasher13 {
+    fn default() -> Self {
+        Self::new_with_keys(0, 0)
+    }
+}
+
+impl Hasher for SipHasher13 {
+    fn write(&mut self, bytes: &[u8]) {
+        self.length += bytes.len();
+        for byte in bytes {
+            self.tail |= (*byte as u64) << (8 * self.tail_len);
+            self.tail_len += 1;
+            if self.tail_len == 8 {
+                self.compress(self.tail);
+                self.tail = 0;
+                self.tail_len = 0;
+            }
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = self.clone();
+        let last = ((self.length as u64 & 0xff) << 56) | self.tail;
+        state.compress(last);
+        state.v2 ^= 0xff;
+        state.round();
+        state.round();
+        state.round();
+        state.v0 ^ state.v1 ^ state.v2 ^ state.v3
+    }
+}
@@ -0,0 +1,99 @@
+use std::iter::FusedIterator;
+
+use super::entry::Entry;
+use crate::linked_list::{self, LinkedList};
+
+/// An iterator over the key/value pairs of a `HashTable`, in row order.
+///
+/// Created by `HashTable::iter`.
+pub struct Iter<'a, K, V> {
+    pub(super) rows: std::slice::Iter<'a, LinkedList<Entry<K, V>>>,
+    pub(super) entries: Option<linked_list::iter::Iter<'a, Entry<K, V>>>,
+    pub(super) remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        loop {
+            if let Some(entry) = self.entries.as_mut().and_then(Iterator::next) {
+                self.remaining -= 1;
+                return Some((&entry.key, &entry.value));
+            }
+            self.entries = Some(self.rows.next()?.iter());
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Iter { rows: self.rows.clone(), entries: self.entries.clone(), remaining: self.remaining }
+    }
+}
+
+/// An iterator over the keys of a `HashTable`, in row order.
+///
+/// Created by `HashTable::keys`.
+pub struct Keys<'a, K, V> {
+    pub(super) iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    fn clone(&self) -> Self {
+        Keys { iter: self.iter.clone() }
+    }
+}
+
+/// An iterator over the values of a `HashTable`, in row order.
+///
+/// Created by `HashTable::values`.
+pub struct Values<'a, K, V> {
+    pub(super) iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+impl<K, V> Clone for Values<'_, K, V> {
+    fn clone(&self) -> Self {
+        Values { iter: self.iter.clone() }
+    }
+}
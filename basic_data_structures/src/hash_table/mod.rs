@@ -1,65 +1,348 @@
-use std::f32::consts::PI;
+use std::{
+    borrow::Borrow,
+    fmt::Display,
+    hash::{BuildHasher, Hash},
+};
 
 use crate::{linked_list::LinkedList, queue::Queue};
 
-pub struct HashTable {
+pub mod entry;
+use entry::Entry;
+
+pub mod iter;
+use iter::{Iter, Keys, Values};
+
+pub mod hasher;
+pub use hasher::{ByteSum, Fnv1a, SipHash, StringFolding};
+
+pub mod open_addressing;
+pub use open_addressing::OpenHashTable;
+
+/// A hash map that resolves collisions with separate chaining: every row of the table is a
+/// `LinkedList` holding the entries whose keys hash to that row.
+///
+/// The table keeps its load factor (entries per row) under a configurable maximum by doubling
+/// the number of rows and rehashing every entry when an insertion goes past it. Removals halve
+/// the rows again once the load factor drops below a quarter of the maximum, but never below
+/// the number of rows the table was created with.
+///
+/// # Type parameters
+///
+/// * `K`: The type of the keys, which must implement `Hash` and `Eq`.
+/// * `V`: The type of the values stored alongside the keys.
+/// * `S`: The `BuildHasher` used to pick the row of every key. Defaults to `SipHash`; the
+///   `hasher` module also ships `ByteSum`, `StringFolding` and `Fnv1a`.
+pub struct HashTable<K, V, S = SipHash> {
     size: usize,
     rows_count: usize,
-    rows: LinkedList<LinkedList<String>>
+    min_rows_count: usize,
+    max_load_factor: f32,
+    hash_builder: S,
+    rows: Vec<LinkedList<Entry<K, V>>>,
 }
 
-impl HashTable {
+/// The maximum load factor used by the constructors that don't take one.
+pub const DEFAULT_LOAD_FACTOR: f32 = 0.75;
+
+const DEFAULT_ROWS_COUNT: usize = 10;
+
+impl<K: Hash + Eq, V> HashTable<K, V, SipHash> {
+    /// Creates a new, empty hash table with 10 rows.
     pub fn new() -> Self {
-        let mut rows = LinkedList::new();
-        let rows_count = 10;
-        for _ in 0..rows_count {
-            rows.append(LinkedList::new());
-        }
-        HashTable { size: 0, rows: rows, rows_count }
+        Self::with_rows_and_load_factor(DEFAULT_ROWS_COUNT, DEFAULT_LOAD_FACTOR)
     }
+
+    /// Creates a new, empty hash table with the given number of rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows_count` - The initial number of rows (buckets) of the table. A value of `0` is
+    ///   treated as `1`. The table never shrinks below this number of rows.
     pub fn new_with_custom_size(rows_count: u32) -> Self {
-        let mut rows = LinkedList::new();
+        Self::with_rows_and_load_factor(rows_count as usize, DEFAULT_LOAD_FACTOR)
+    }
+
+    /// Creates a new, empty hash table with enough rows to hold `capacity` entries without
+    /// growing.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_load_factor(capacity, DEFAULT_LOAD_FACTOR)
+    }
+
+    /// Creates a new, empty hash table with 10 rows that grows whenever its load factor goes
+    /// past `max_load_factor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_load_factor` is not a positive, finite number.
+    pub fn with_load_factor(max_load_factor: f32) -> Self {
+        Self::with_rows_and_load_factor(DEFAULT_ROWS_COUNT, max_load_factor)
+    }
+
+    /// Creates a new, empty hash table with enough rows to hold `capacity` entries without
+    /// going past `max_load_factor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_load_factor` is not a positive, finite number.
+    pub fn with_capacity_and_load_factor(capacity: usize, max_load_factor: f32) -> Self {
+        let rows_count = (capacity as f32 / max_load_factor).ceil() as usize;
+        Self::with_rows_and_load_factor(rows_count, max_load_factor)
+    }
+
+    fn with_rows_and_load_factor(rows_count: usize, max_load_factor: f32) -> Self {
+        Self::with_rows_load_factor_and_hasher(rows_count, max_load_factor, SipHash::new())
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> HashTable<K, V, S> {
+    /// Creates a new, empty hash table with 10 rows that uses `hash_builder` to hash its keys.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_rows_load_factor_and_hasher(DEFAULT_ROWS_COUNT, DEFAULT_LOAD_FACTOR, hash_builder)
+    }
+
+    /// Creates a new, empty hash table with enough rows to hold `capacity` entries without
+    /// growing, which uses `hash_builder` to hash its keys.
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let rows_count = (capacity as f32 / DEFAULT_LOAD_FACTOR).ceil() as usize;
+        Self::with_rows_load_factor_and_hasher(rows_count, DEFAULT_LOAD_FACTOR, hash_builder)
+    }
+
+    fn with_rows_load_factor_and_hasher(
+        rows_count: usize,
+        max_load_factor: f32,
+        hash_builder: S,
+    ) -> Self {
+        assert!(
+            max_load_factor.is_finite() && max_load_factor > 0.0,
+            "the maximum load factor must be a positive number"
+        );
+        let rows_count = rows_count.max(1);
+        HashTable {
+            size: 0,
+            rows_count,
+            min_rows_count: rows_count,
+            max_load_factor,
+            hash_builder,
+            rows: Self::empty_rows(rows_count),
+        }
+    }
+
+    fn empty_rows(rows_count: usize) -> Vec<LinkedList<Entry<K, V>>> {
+        let mut rows = Vec::with_capacity(rows_count);
         for _ in 0..rows_count {
-            rows.append(LinkedList::new());
+            rows.push(LinkedList::new());
         }
-        HashTable { size: 0, rows: rows, rows_count: rows_count as usize }
-    }
-
-    fn hash(&self, elem: String) -> usize {
-        let sum: u32 = elem.bytes().into_iter().map(|i|{ i as u32}).sum();
-        (sum % self.rows_count as u32) as usize
-    }
-
-    fn hash_with_string_folding(&self, elem: String) -> usize {
-        let sum: u32 = elem
-            .bytes()
-            .into_iter()
-            .map(|i|{ 
-                let mult = if i % 4 == 0 { 1 } else { 255 };
-                i as u32 * mult
-            })
-            .sum();
-        (sum % self.rows_count as u32) as usize
-    }
-
-    pub fn insert(&mut self, elem: String) {
-        let hash = if self.rows_count > 30 {
-            self.hash_with_string_folding(elem.clone())
-        } else {
-            self.hash(elem.clone())
-        };
-        self.rows.for_each_mut(|list, i: usize| {
-            if i != hash { return; }
-            list.append(elem.clone());
-        });
+        rows
+    }
+
+    fn row_index<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        (self.hash_builder.hash_one(key) % self.rows_count as u64) as usize
+    }
+
+    /// Inserts a key/value pair into the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to insert.
+    /// * `value` - The value to associate with `key`.
+    ///
+    /// # Returns
+    ///
+    /// The value previously associated with `key`, or `None` if the key was not present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old_value) = self.get_mut(&key) {
+            return Some(std::mem::replace(old_value, value));
+        }
+        let index = self.row_index(&key);
+        self.rows[index].append(Entry::new(key, value));
         self.size += 1;
+        if self.load_factor() > self.max_load_factor {
+            self.resize(self.rows_count * 2);
+        }
+        None
+    }
+
+    /// Returns a reference to the value associated with `key`, if any.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let mut current = &self.rows[self.row_index(key)].head;
+        while let Some(node) = current {
+            if node.data.key.borrow() == key {
+                return Some(&node.data.value);
+            }
+            current = &node.next;
+        }
+        None
+    }
+
+    /// Returns a mutable reference to the value associated with `key`, if any.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.row_index(key);
+        let mut current = &mut self.rows[index].head;
+        while let Some(node) = current {
+            if node.data.key.borrow() == key {
+                return Some(&mut node.data.value);
+            }
+            current = &mut node.next;
+        }
+        None
+    }
+
+    /// Removes `key` from the table.
+    ///
+    /// # Returns
+    ///
+    /// The value that was associated with `key`, or `None` if the key was not present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.row_index(key);
+        let row = &mut self.rows[index];
+        let mut current = &mut row.head;
+        while current
+            .as_ref()
+            .is_some_and(|node| node.data.key.borrow() != key)
+        {
+            current = &mut current.as_mut().unwrap().next;
+        }
+        let node = current.take()?;
+        *current = node.next;
+        row.size -= 1;
+        self.size -= 1;
+        if self.rows_count > self.min_rows_count && self.load_factor() < self.max_load_factor / 4.0 {
+            self.resize((self.rows_count / 2).max(self.min_rows_count));
+        }
+        Some(node.data.value)
     }
 
-    pub fn print(& self) {
+    /// Returns `true` if the table contains a value for `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of entries stored in the table.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the table holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Removes every entry and shrinks the table back to the number of rows it was created with.
+    pub fn clear(&mut self) {
+        self.rows_count = self.min_rows_count;
+        self.rows = Self::empty_rows(self.rows_count);
+        self.size = 0;
+    }
+
+    /// Returns the average number of entries per row.
+    pub fn load_factor(&self) -> f32 {
+        self.size as f32 / self.rows_count as f32
+    }
+
+    /// Returns the maximum load factor the table allows before growing.
+    pub fn max_load_factor(&self) -> f32 {
+        self.max_load_factor
+    }
+
+    /// Returns a reference to the table's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Returns the current number of rows (buckets) of the table.
+    pub fn rows_count(&self) -> usize {
+        self.rows_count
+    }
+
+    /// Replaces the rows of the table with `rows_count` empty ones and moves every entry into
+    /// the row its key hashes to under the new size. Nodes are relinked, not reallocated.
+    fn resize(&mut self, rows_count: usize) {
+        let old_rows = std::mem::replace(&mut self.rows, Self::empty_rows(rows_count));
+        self.rows_count = rows_count;
+        for mut row in old_rows {
+            let mut current = row.head.take();
+            while let Some(mut node) = current {
+                current = node.next.take();
+                let index = self.row_index(&node.data.key);
+                let target = &mut self.rows[index];
+                node.next = target.head.take();
+                target.head = Some(node);
+                target.size += 1;
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> Default for HashTable<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> HashTable<K, V, S> {
+    /// Returns an iterator over the key/value pairs of the table, in no particular order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { rows: self.rows.iter(), entries: None, remaining: self.size }
+    }
+
+    /// Returns an iterator over the keys of the table, in no particular order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Returns an iterator over the values of the table, in no particular order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashTable<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> FromIterator<(K, V)> for HashTable<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut table = Self::default();
+        table.extend(iter);
+        table
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for HashTable<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Display, V: Display, S> HashTable<K, V, S> {
+    pub fn print(&self) {
         println!("\nHashTable:");
-        self.rows.for_each(|list, i: usize| {
+        for (i, row) in self.rows.iter().enumerate() {
             print!("{}-| ", i);
-            list.print();
-        });
+            row.print();
+        }
     }
-}
\ No newline at end of file
+}
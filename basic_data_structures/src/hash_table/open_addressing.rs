@@ -0,0 +1,464 @@
+use std::{
+    borrow::Borrow,
+    fmt::Display,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+};
+
+use super::{entry::Entry, SipHash, DEFAULT_LOAD_FACTOR, DEFAULT_ROWS_COUNT};
+
+/// An entry together with the full hash of its key, so moving it around never rehashes it.
+struct Slot<K, V> {
+    hash: u64,
+    entry: Entry<K, V>,
+}
+
+/// A hash map that resolves collisions with open addressing: every entry lives directly in a
+/// single array of rows, and a key that collides is stored in the next free row instead.
+///
+/// Rows are filled with Robin Hood linear probing: while looking for a free row, an entry that
+/// has probed further from its home row than the entry sitting in a row takes that row and the
+/// displaced entry keeps probing. That keeps every probe sequence short and lets lookups stop
+/// early. Removals use backward-shift deletion, moving the following entries one row back
+/// instead of leaving tombstones behind.
+///
+/// It has the same public API as `HashTable`, so either one can be swapped for the other, but
+/// it doesn't allocate per entry. The maximum load factor must stay below `1.0`, since every
+/// entry needs its own row.
+///
+/// # Type parameters
+///
+/// * `K`: The type of the keys, which must implement `Hash` and `Eq`.
+/// * `V`: The type of the values stored alongside the keys.
+/// * `S`: The `BuildHasher` used to hash the keys. Defaults to `SipHash`.
+pub struct OpenHashTable<K, V, S = SipHash> {
+    size: usize,
+    rows_count: usize,
+    min_rows_count: usize,
+    max_load_factor: f32,
+    hash_builder: S,
+    rows: Vec<Option<Slot<K, V>>>,
+}
+
+impl<K: Hash + Eq, V> OpenHashTable<K, V, SipHash> {
+    /// Creates a new, empty hash table with 10 rows.
+    pub fn new() -> Self {
+        Self::with_rows_and_load_factor(DEFAULT_ROWS_COUNT, DEFAULT_LOAD_FACTOR)
+    }
+
+    /// Creates a new, empty hash table with the given number of rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows_count` - The initial number of rows of the table. A value of `0` is treated as
+    ///   `1`. The table never shrinks below this number of rows.
+    pub fn new_with_custom_size(rows_count: u32) -> Self {
+        Self::with_rows_and_load_factor(rows_count as usize, DEFAULT_LOAD_FACTOR)
+    }
+
+    /// Creates a new, empty hash table with enough rows to hold `capacity` entries without
+    /// growing.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_load_factor(capacity, DEFAULT_LOAD_FACTOR)
+    }
+
+    /// Creates a new, empty hash table with 10 rows that grows whenever its load factor goes
+    /// past `max_load_factor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_load_factor` is not between `0.0` and `1.0`, both excluded.
+    pub fn with_load_factor(max_load_factor: f32) -> Self {
+        Self::with_rows_and_load_factor(DEFAULT_ROWS_COUNT, max_load_factor)
+    }
+
+    /// Creates a new, empty hash table with enough rows to hold `capacity` entries without
+    /// going past `max_load_factor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_load_factor` is not between `0.0` and `1.0`, both excluded.
+    pub fn with_capacity_and_load_factor(capacity: usize, max_load_factor: f32) -> Self {
+        let rows_count = (capacity as f32 / max_load_factor).ceil() as usize;
+        Self::with_rows_and_load_factor(rows_count, max_load_factor)
+    }
+
+    fn with_rows_and_load_factor(rows_count: usize, max_load_factor: f32) -> Self {
+        Self::with_rows_load_factor_and_hasher(rows_count, max_load_factor, SipHash::new())
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> OpenHashTable<K, V, S> {
+    /// Creates a new, empty hash table with 10 rows that uses `hash_builder` to hash its keys.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_rows_load_factor_and_hasher(DEFAULT_ROWS_COUNT, DEFAULT_LOAD_FACTOR, hash_builder)
+    }
+
+    /// Creates a new, empty hash table with enough rows to hold `capacity` entries without
+    /// growing, which uses `hash_builder` to hash its keys.
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let rows_count = (capacity as f32 / DEFAULT_LOAD_FACTOR).ceil() as usize;
+        Self::with_rows_load_factor_and_hasher(rows_count, DEFAULT_LOAD_FACTOR, hash_builder)
+    }
+
+    fn with_rows_load_factor_and_hasher(
+        rows_count: usize,
+        max_load_factor: f32,
+        hash_builder: S,
+    ) -> Self {
+        assert!(
+            max_load_factor > 0.0 && max_load_factor < 1.0,
+            "the maximum load factor of an open addressing table must be between 0 and 1"
+        );
+        let rows_count = rows_count.max(1);
+        OpenHashTable {
+            size: 0,
+            rows_count,
+            min_rows_count: rows_count,
+            max_load_factor,
+            hash_builder,
+            rows: Self::empty_rows(rows_count),
+        }
+    }
+
+    fn empty_rows(rows_count: usize) -> Vec<Option<Slot<K, V>>> {
+        let mut rows = Vec::with_capacity(rows_count);
+        rows.resize_with(rows_count, || None);
+        rows
+    }
+
+    fn home_index(&self, hash: u64) -> usize {
+        (hash % self.rows_count as u64) as usize
+    }
+
+    /// Returns how many rows away from its home row an entry with the given hash sits when it
+    /// is stored at `index`.
+    fn probe_distance(&self, hash: u64, index: usize) -> usize {
+        (index + self.rows_count - self.home_index(hash)) % self.rows_count
+    }
+
+    fn find_index<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.hash_builder.hash_one(key);
+        let mut index = self.home_index(hash);
+        let mut distance = 0;
+        while let Some(slot) = &self.rows[index] {
+            // Robin Hood keeps the rows ordered by probe distance, so once an entry is closer
+            // to home than our key would be, the key can't be further along.
+            if self.probe_distance(slot.hash, index) < distance {
+                return None;
+            }
+            if slot.hash == hash && slot.entry.key.borrow() == key {
+                return Some(index);
+            }
+            index = (index + 1) % self.rows_count;
+            distance += 1;
+        }
+        None
+    }
+
+    /// Stores a slot whose key is known not to be in the table yet.
+    fn insert_slot(&mut self, mut slot: Slot<K, V>) {
+        let mut index = self.home_index(slot.hash);
+        let mut distance = 0;
+        loop {
+            let existing_distance = match &self.rows[index] {
+                None => {
+                    self.rows[index] = Some(slot);
+                    return;
+                }
+                Some(existing) => self.probe_distance(existing.hash, index),
+            };
+            if existing_distance < distance {
+                std::mem::swap(self.rows[index].as_mut().unwrap(), &mut slot);
+                distance = existing_distance;
+            }
+            index = (index + 1) % self.rows_count;
+            distance += 1;
+        }
+    }
+
+    /// Inserts a key/value pair into the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to insert.
+    /// * `value` - The value to associate with `key`.
+    ///
+    /// # Returns
+    ///
+    /// The value previously associated with `key`, or `None` if the key was not present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old_value) = self.get_mut(&key) {
+            return Some(std::mem::replace(old_value, value));
+        }
+        if (self.size + 1) as f32 / self.rows_count as f32 > self.max_load_factor {
+            self.resize(self.rows_count * 2);
+        }
+        let hash = self.hash_builder.hash_one(&key);
+        self.insert_slot(Slot { hash, entry: Entry::new(key, value) });
+        self.size += 1;
+        None
+    }
+
+    /// Returns a reference to the value associated with `key`, if any.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_index(key)?;
+        self.rows[index].as_ref().map(|slot| &slot.entry.value)
+    }
+
+    /// Returns a mutable reference to the value associated with `key`, if any.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_index(key)?;
+        self.rows[index].as_mut().map(|slot| &mut slot.entry.value)
+    }
+
+    /// Removes `key` from the table.
+    ///
+    /// # Returns
+    ///
+    /// The value that was associated with `key`, or `None` if the key was not present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find_index(key)?;
+        let removed = self.rows[index].take()?;
+        // Shift the entries that follow back by one row until reaching an empty row or an
+        // entry that already sits in its home row.
+        let mut hole = index;
+        loop {
+            let next = (hole + 1) % self.rows_count;
+            match &self.rows[next] {
+                Some(slot) if self.probe_distance(slot.hash, next) > 0 => {
+                    self.rows[hole] = self.rows[next].take();
+                    hole = next;
+                }
+                _ => break,
+            }
+        }
+        self.size -= 1;
+        if self.rows_count > self.min_rows_count && self.load_factor() < self.max_load_factor / 4.0 {
+            self.resize((self.rows_count / 2).max(self.min_rows_count));
+        }
+        Some(removed.entry.value)
+    }
+
+    /// Returns `true` if the table contains a value for `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find_index(key).is_some()
+    }
+
+    /// Returns the number of entries stored in the table.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the table holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Removes every entry and shrinks the table back to the number of rows it was created with.
+    pub fn clear(&mut self) {
+        self.rows_count = self.min_rows_count;
+        self.rows = Self::empty_rows(self.rows_count);
+        self.size = 0;
+    }
+
+    /// Returns the fraction of rows that hold an entry.
+    pub fn load_factor(&self) -> f32 {
+        self.size as f32 / self.rows_count as f32
+    }
+
+    /// Returns the maximum load factor the table allows before growing.
+    pub fn max_load_factor(&self) -> f32 {
+        self.max_load_factor
+    }
+
+    /// Returns a reference to the table's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Returns the current number of rows of the table.
+    pub fn rows_count(&self) -> usize {
+        self.rows_count
+    }
+
+    /// Replaces the rows of the table with `rows_count` empty ones and stores every entry again
+    /// under the new size, reusing the hashes saved alongside them.
+    fn resize(&mut self, rows_count: usize) {
+        let old_rows = std::mem::replace(&mut self.rows, Self::empty_rows(rows_count));
+        self.rows_count = rows_count;
+        for slot in old_rows.into_iter().flatten() {
+            self.insert_slot(slot);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> Default for OpenHashTable<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> OpenHashTable<K, V, S> {
+    /// Returns an iterator over the key/value pairs of the table, in no particular order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { rows: self.rows.iter(), remaining: self.size }
+    }
+
+    /// Returns an iterator over the keys of the table, in no particular order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Returns an iterator over the values of the table, in no particular order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a OpenHashTable<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> FromIterator<(K, V)> for OpenHashTable<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut table = Self::default();
+        table.extend(iter);
+        table
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for OpenHashTable<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// An iterator over the key/value pairs of an `OpenHashTable`, in row order.
+///
+/// Created by `OpenHashTable::iter`.
+pub struct Iter<'a, K, V> {
+    rows: std::slice::Iter<'a, Option<Slot<K, V>>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let slot = self.rows.by_ref().flatten().next()?;
+        self.remaining -= 1;
+        Some((&slot.entry.key, &slot.entry.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Iter { rows: self.rows.clone(), remaining: self.remaining }
+    }
+}
+
+/// An iterator over the keys of an `OpenHashTable`, in row order.
+///
+/// Created by `OpenHashTable::keys`.
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    fn clone(&self) -> Self {
+        Keys { iter: self.iter.clone() }
+    }
+}
+
+/// An iterator over the values of an `OpenHashTable`, in row order.
+///
+/// Created by `OpenHashTable::values`.
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+impl<K, V> Clone for Values<'_, K, V> {
+    fn clone(&self) -> Self {
+        Values { iter: self.iter.clone() }
+    }
+}
+
+impl<K: Display, V: Display, S> OpenHashTable<K, V, S> {
+    pub fn print(&self) {
+        println!("\nOpenHashTable:");
+        for (i, row) in self.rows.iter().enumerate() {
+            match row {
+                Some(slot) => println!("{}-| {}", i, slot.entry),
+                None => println!("{}-| null", i),
+            }
+        }
+    }
+}
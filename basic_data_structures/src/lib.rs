@@ -1,7 +1,24 @@
 pub mod linked_list;
+pub mod doubly_linked_list;
 pub mod queue;
 pub mod stack;
 pub mod hash_table;
+pub mod hash_set;
+pub mod priority_queue;
+pub mod persistent_list;
+pub mod avl_tree;
+pub mod skip_list;
+pub mod trie;
+pub mod graph;
+pub mod disjoint_set;
+pub mod lru_cache;
+pub mod probabilistic;
+pub mod sync;
+
+mod bounds;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
 
 #[cfg(test)]
 mod test;
\ No newline at end of file
@@ -0,0 +1,152 @@
+use super::{
+    node::{Link, Node},
+    LinkedList,
+};
+
+/// A read-only cursor over a `LinkedList`.
+///
+/// The cursor starts at the head of the list and can only walk forward. Once it moves past the
+/// tail it points at no element and stays there.
+///
+/// Created by `LinkedList::cursor_front`.
+pub struct Cursor<'a, T> {
+    current: Option<&'a Node<T>>,
+    index: usize,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Returns the index of the element the cursor points at, or `None` if it is past the end.
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Returns a reference to the element the cursor points at, or `None` if it is past the end.
+    pub fn current(&self) -> Option<&'a T> {
+        self.current.map(|node| &*node.data)
+    }
+
+    /// Returns a reference to the element after the current one, without moving the cursor.
+    pub fn peek_next(&self) -> Option<&'a T> {
+        self.current?.next.as_deref().map(|node| &*node.data)
+    }
+
+    /// Moves the cursor to the next element. Does nothing if the cursor is past the end.
+    pub fn move_next(&mut self) {
+        if let Some(node) = self.current {
+            self.current = node.next.as_deref();
+            self.index += 1;
+        }
+    }
+}
+
+/// A cursor over a `LinkedList` that can edit the list around its position.
+///
+/// The cursor starts at the head of the list and can only walk forward. Once it moves past the
+/// tail it points at no element and stays there.
+///
+/// Created by `LinkedList::cursor_front_mut`.
+pub struct CursorMut<'a, T> {
+    // The link that owns the current node: the head of the list or the `next` of the previous
+    // node. It is only `None` while the cursor is being moved.
+    link: Option<&'a mut Link<T>>,
+    size: &'a mut usize,
+    index: usize,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    fn link(&mut self) -> &mut Link<T> {
+        self.link.as_mut().unwrap()
+    }
+
+    /// Returns the index of the element the cursor points at, or `None` if it is past the end.
+    pub fn index(&self) -> Option<usize> {
+        let link = self.link.as_ref().unwrap();
+        link.as_ref().map(|_| self.index)
+    }
+
+    /// Returns a mutable reference to the element the cursor points at, or `None` if it is past
+    /// the end.
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.link().as_mut().map(|node| &mut *node.data)
+    }
+
+    /// Returns a mutable reference to the element after the current one, without moving the
+    /// cursor.
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        self.link().as_mut()?.next.as_mut().map(|node| &mut *node.data)
+    }
+
+    /// Moves the cursor to the next element. Does nothing if the cursor is past the end.
+    pub fn move_next(&mut self) {
+        let mut link = self.link.take().unwrap();
+        if link.is_some() {
+            link = &mut link.as_mut().unwrap().next;
+            self.index += 1;
+        }
+        self.link = Some(link);
+    }
+
+    /// Inserts `value` right after the current element. The cursor keeps pointing at the same
+    /// element.
+    ///
+    /// If the cursor is past the end, `value` is appended to the end of the list and the cursor
+    /// stays past the end.
+    pub fn insert_after(&mut self, value: T) {
+        let mut new_node = Box::new(Node::new(value));
+        *self.size += 1;
+        match self.link().as_mut() {
+            Some(node) => {
+                new_node.next = node.next.take();
+                node.next = Some(new_node);
+            }
+            None => {
+                *self.link() = Some(new_node);
+                self.move_next();
+            }
+        }
+    }
+
+    /// Removes the current element and returns it. The cursor moves to the element that
+    /// followed it.
+    ///
+    /// # Returns
+    ///
+    /// The removed value, or `None` if the cursor is past the end.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let link = self.link();
+        let node = link.take()?;
+        *link = node.next;
+        *self.size -= 1;
+        Some(*node.data)
+    }
+
+    /// Splits the list in two after the current element.
+    ///
+    /// # Returns
+    ///
+    /// A new list with every element after the current one. If the cursor is past the end,
+    /// the returned list is empty.
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        let index = self.index;
+        let Some(node) = self.link().as_mut() else {
+            return LinkedList::new();
+        };
+        let head = node.next.take();
+        let size = *self.size - index - 1;
+        *self.size = index + 1;
+        LinkedList { head, size }
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Returns a read-only cursor pointing at the head of the list.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor { current: self.head.as_deref(), index: 0 }
+    }
+
+    /// Returns a cursor pointing at the head of the list that can insert and remove elements
+    /// in place.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { link: Some(&mut self.head), size: &mut self.size, index: 0 }
+    }
+}
@@ -1,9 +1,16 @@
-use std::fmt::{Display, Write};
+use std::fmt::Display;
 
 // Importing the Node and Link types from the node module
 pub mod node;
 use node::Link;
 
+pub mod iter;
+use iter::{Iter, IterMut};
+
+pub mod cursor;
+
+mod sort;
+
 /// A singly linked list that stores values of type `T`.
 ///
 /// # Type parameters
@@ -25,13 +32,26 @@ pub struct LinkedList<T> {
     pub size: usize,
 }
 
-impl<T: Clone + Display> Clone for LinkedList<T> {
+impl<T: Clone> Clone for LinkedList<T> {
+    /// Copies the values one by one, since cloning the head would clone the whole chain
+    /// recursively and overflow the stack on long lists.
     fn clone(&self) -> Self {
-        Self { head: self.head.clone(), size: self.size.clone() }
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    /// Unlinks the nodes one by one, since dropping the head would drop the whole chain
+    /// recursively and overflow the stack on long lists.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
     }
 }
 
-impl<T: Clone + Display> Display for LinkedList<T> {
+impl<T: Display> Display for LinkedList<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut current = &self.head;
         let mut text = String::new();
@@ -44,7 +64,7 @@ impl<T: Clone + Display> Display for LinkedList<T> {
     }
 }
 
-impl<T: Clone + Display> LinkedList<T> {
+impl<T> LinkedList<T> {
     /// Creates a new, empty linked list.
     ///
     /// # Returns
@@ -66,31 +86,31 @@ impl<T: Clone + Display> LinkedList<T> {
         }
     }
 
-    pub fn for_each_mut<F>(&mut self, lamda: F)
-        where F: Fn(&mut T, usize)
+    /// Returns an iterator over references to the values of the list, from head to tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref(), remaining: self.size }
+    }
+
+    /// Returns an iterator over mutable references to the values of the list, from head to tail.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head.as_deref_mut(), remaining: self.size }
+    }
+
+    /// Calls `lamda` with a mutable reference to every value of the list and its index.
+    pub fn for_each_mut<F>(&mut self, mut lamda: F)
+        where F: FnMut(&mut T, usize)
     {
-        if self.head.is_none() { return; }
-        let mut current = self.head.as_mut().unwrap();
-        let mut cont: usize = 0;
-
-        while current.next.is_some() {
-            lamda(&mut current.data, cont);
-            cont += 1;
-            current = current.next.as_mut().unwrap()
+        for (cont, value) in self.iter_mut().enumerate() {
+            lamda(value, cont);
         }
     }
 
-    pub fn for_each<F>(&self, lamda: F)
-        where F: Fn(&T, usize)
+    /// Calls `lamda` with a reference to every value of the list and its index.
+    pub fn for_each<F>(&self, mut lamda: F)
+        where F: FnMut(&T, usize)
     {
-        if self.head.is_none() { return; }
-        let mut current = self.head.as_ref().unwrap();
-        let mut cont: usize = 0;
-
-        while current.next.is_some() {
-            lamda(&current.data, cont);
-            current = current.next.as_ref().unwrap();
-            cont += 1;
+        for (cont, value) in self.iter().enumerate() {
+            lamda(value, cont);
         }
     }
 
@@ -124,27 +144,6 @@ impl<T: Clone + Display> LinkedList<T> {
         Some(*last_node.data)
     }
 
-    /// Prints the contents of the linked list, starting from the head node.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use linkedlist::LinkedList;
-    ///
-    /// let mut list = LinkedList::new();
-    /// list.prepend(1);
-    /// list.prepend(2);
-    /// list.print();
-    /// ```
-    pub fn print(&self) {
-        let mut current = &self.head;
-        while let Some(node) = current {
-            print!("{} -> ", node.data);
-            current = &node.next;
-        }
-        print!("null\n\n");
-    }
-
     /// Checks whether the linked list is empty.
     ///
     /// # Returns
@@ -165,6 +164,33 @@ impl<T: Clone + Display> LinkedList<T> {
         self.head.is_none()
     }
 
+    /// Removes and returns the first node in the linked list.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(T)` - The value of the removed node, if the linked list was not empty.
+    /// * `None` - If the linked list was empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.head.is_none() {
+            return None;
+        }
+        let top_node = self.head.take().unwrap();
+        self.head = top_node.next;
+        self.size -= 1;
+        return Some(*top_node.data);
+    }
+
+    /// Returns the number of nodes in the linked list.
+    ///
+    /// # Returns
+    ///
+    /// The number of nodes in the linked list, as an unsigned 32-bit integer.
+    pub fn size(&self) -> u32 {
+        self.size as u32
+    }
+}
+
+impl<T: Clone> LinkedList<T> {
     /// Returns the value of the first node in the linked list, without removing it.
     ///
     /// # Returns
@@ -299,29 +325,27 @@ impl<T: Clone + Display> LinkedList<T> {
 
         None
     }
+}
 
-    /// Removes and returns the first node in the linked list.
-    ///
-    /// # Returns
+impl<T: Display> LinkedList<T> {
+    /// Prints the contents of the linked list, starting from the head node.
     ///
-    /// * `Some(T)` - The value of the removed node, if the linked list was not empty.
-    /// * `None` - If the linked list was empty.
-    pub fn pop(&mut self) -> Option<T> {
-        if self.head.is_none() {
-            return None;
-        }
-        let top_node = self.head.take().unwrap();
-        self.head = top_node.next;
-        self.size -= 1;
-        return Some(*top_node.data);
-    }
-
-    /// Returns the number of nodes in the linked list.
+    /// # Examples
     ///
-    /// # Returns
+    /// ```
+    /// use linkedlist::LinkedList;
     ///
-    /// The number of nodes in the linked list, as an unsigned 32-bit integer.
-    pub fn size(&self) -> u32 {
-        self.size as u32
+    /// let mut list = LinkedList::new();
+    /// list.prepend(1);
+    /// list.prepend(2);
+    /// list.print();
+    /// ```
+    pub fn print(&self) {
+        let mut current = &self.head;
+        while let Some(node) = current {
+            print!("{} -> ", node.data);
+            current = &node.next;
+        }
+        print!("null\n\n");
     }
 }
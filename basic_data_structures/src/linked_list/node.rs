@@ -6,7 +6,7 @@ pub struct Node<T> {
     pub next: Link<T>,
 }
 
-impl<T: Clone> Node<T> {
+impl<T> Node<T> {
     pub fn new(value: T) -> Node<T> {
         Node {
             data: Box::new(value),
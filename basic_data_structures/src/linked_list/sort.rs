@@ -0,0 +1,122 @@
+use std::cmp::Ordering;
+
+use super::{node::Link, LinkedList};
+
+impl<T> LinkedList<T> {
+    /// Sorts the list in ascending order.
+    ///
+    /// The sort is a stable merge sort in O(n log n) that relinks the existing nodes, so no
+    /// value is moved or cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use basic_data_structures::linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = [3, 1, 2].into_iter().collect();
+    /// list.sort();
+    /// assert!(list.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list with `compare`, keeping equal values in their original order.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let head = self.head.take();
+        self.head = merge_sort(head, self.size, &mut compare);
+    }
+
+    /// Sorts the list by the key `key` extracts from each value, keeping values with equal keys
+    /// in their original order. The key is extracted again at every comparison.
+    pub fn sort_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+
+    /// Checks whether every value is less than or equal to the one after it.
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        let mut values = self.iter();
+        let Some(mut previous) = values.next() else {
+            return true;
+        };
+        for value in values {
+            if previous > value {
+                return false;
+            }
+            previous = value;
+        }
+        true
+    }
+
+    /// Moves every value of `other` into this list, keeping it sorted. Both lists must already
+    /// be sorted in ascending order; values of this list come before equal values of `other`.
+    ///
+    /// The nodes of `other` are relinked, in O(n + m).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use basic_data_structures::linked_list::LinkedList;
+    ///
+    /// let mut odds: LinkedList<_> = [1, 3, 5].into_iter().collect();
+    /// odds.merge([2, 4].into_iter().collect());
+    /// assert!(odds.iter().eq(&[1, 2, 3, 4, 5]));
+    /// ```
+    pub fn merge(&mut self, mut other: LinkedList<T>)
+    where
+        T: Ord,
+    {
+        let head = self.head.take();
+        self.head = merge_links(head, other.head.take(), &mut T::cmp);
+        self.size += other.size;
+        other.size = 0;
+    }
+}
+
+/// Sorts the `len` nodes starting at `head` by sorting each half and merging them.
+fn merge_sort<T, F>(mut head: Link<T>, len: usize, compare: &mut F) -> Link<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if len < 2 {
+        return head;
+    }
+    let mut last_of_first = head.as_mut().unwrap();
+    for _ in 1..len / 2 {
+        last_of_first = last_of_first.next.as_mut().unwrap();
+    }
+    let second = last_of_first.next.take();
+    let first = merge_sort(head, len / 2, compare);
+    let second = merge_sort(second, len - len / 2, compare);
+    merge_links(first, second, compare)
+}
+
+/// Merges two sorted chains of nodes into one, taking from `a` on ties so the merge is stable.
+fn merge_links<T, F>(mut a: Link<T>, mut b: Link<T>, compare: &mut F) -> Link<T>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut head = None;
+    let mut tail = &mut head;
+    while let (Some(first), Some(second)) = (a.as_ref(), b.as_ref()) {
+        let source = if compare(&second.data, &first.data) == Ordering::Less { &mut b } else { &mut a };
+        let mut node = source.take().unwrap();
+        *source = node.next.take();
+        tail = &mut tail.insert(node).next;
+    }
+    *tail = if a.is_some() { a } else { b };
+    head
+}
@@ -0,0 +1,392 @@
+use std::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+    mem,
+};
+
+use crate::hash_table::{HashTable, SipHash};
+
+struct Slot<K, V> {
+    key: K,
+    value: V,
+    /// The slot used just before this one.
+    prev: Option<usize>,
+    /// The slot used just after this one.
+    next: Option<usize>,
+}
+
+/// A callback that receives the entries evicted from an `LruCache`.
+type OnEvict<K, V> = Box<dyn FnMut(K, V) + Send>;
+
+/// A cache that holds up to `capacity` entries and evicts the least recently used one to make
+/// room for a new one.
+///
+/// Entries live in the slots of a `Vec` and are chained into a doubly linked list by slot
+/// index, from the most recently used to the least recently used one. A `HashTable` maps each
+/// key to its slot, so finding an entry, moving it to the front of the list and unlinking it
+/// are all O(1), and so are `get` and `put`.
+///
+/// `get` counts hits and misses and makes the entry the most recently used one; `peek` does
+/// neither. A callback set with `set_on_evict` is given the entries `put` evicts.
+///
+/// # Type parameters
+///
+/// * `K`: The type of the keys, which must implement `Hash`, `Eq` and `Clone`: each key is
+///   stored both in the table and in its slot.
+/// * `V`: The type of the values.
+/// * `S`: The `BuildHasher` of the underlying table. Defaults to `SipHash`.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::lru_cache::LruCache;
+///
+/// let mut cache = LruCache::new(2);
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+/// assert_eq!(cache.get(&"a"), Some(&1));
+///
+/// // "b" is now the least recently used entry, so it makes room for "c".
+/// cache.put("c", 3);
+/// assert_eq!(cache.peek(&"b"), None);
+/// assert_eq!(cache.hits(), 1);
+/// ```
+pub struct LruCache<K, V, S = SipHash> {
+    table: HashTable<K, usize, S>,
+    slots: Vec<Option<Slot<K, V>>>,
+    /// The slots of `slots` left empty by removals.
+    free: Vec<usize>,
+    /// The most recently used slot.
+    head: Option<usize>,
+    /// The least recently used slot.
+    tail: Option<usize>,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+    on_evict: Option<OnEvict<K, V>>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V, SipHash> {
+    /// Creates a new, empty cache that holds up to `capacity` entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_hasher(capacity, SipHash::new())
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, S: BuildHasher> LruCache<K, V, S> {
+    /// Creates a new, empty cache that holds up to `capacity` entries and uses `hash_builder`
+    /// to hash its keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_hasher(capacity: usize, hash_builder: S) -> Self {
+        assert!(capacity > 0, "an LRU cache needs room for at least one entry");
+        LruCache {
+            table: HashTable::with_capacity_and_hasher(capacity, hash_builder),
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            head: None,
+            tail: None,
+            capacity,
+            hits: 0,
+            misses: 0,
+            on_evict: None,
+        }
+    }
+
+    /// Sets the callback that is given the key and value of every entry `put` evicts. Entries
+    /// removed with `pop`, `pop_lru` or `clear` are not passed to it.
+    ///
+    /// The callback must be `Send`, so that the cache can still be moved to another thread.
+    pub fn set_on_evict(&mut self, on_evict: impl FnMut(K, V) + Send + 'static) {
+        self.on_evict = Some(Box::new(on_evict));
+    }
+
+    /// Returns a reference to the value associated with `key` and makes it the most recently
+    /// used entry. Counts as a hit if the key is in the cache and as a miss otherwise.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let slot = self.touch(key)?;
+        Some(&self.slot(slot).value)
+    }
+
+    /// Returns a mutable reference to the value associated with `key` and makes it the most
+    /// recently used entry. Counts as a hit or a miss, like `get`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let slot = self.touch(key)?;
+        Some(&mut self.slot_mut(slot).value)
+    }
+
+    /// Returns a reference to the value associated with `key`, without changing how recently
+    /// it was used or counting a hit or a miss.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let &slot = self.table.get(key)?;
+        Some(&self.slot(slot).value)
+    }
+
+    /// Returns `true` if `key` is in the cache, without changing how recently it was used or
+    /// counting a hit or a miss.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.table.contains_key(key)
+    }
+
+    /// Inserts a key/value pair into the cache as its most recently used entry. If the cache is
+    /// full and `key` is not in it yet, the least recently used entry is evicted first.
+    ///
+    /// # Returns
+    ///
+    /// The previous value of `key`, if it was already in the cache.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&slot) = self.table.get(&key) {
+            self.detach(slot);
+            self.push_front(slot);
+            return Some(mem::replace(&mut self.slot_mut(slot).value, value));
+        }
+
+        if self.len() == self.capacity {
+            if let Some((key, value)) = self.pop_lru() {
+                if let Some(on_evict) = self.on_evict.as_mut() {
+                    on_evict(key, value);
+                }
+            }
+        }
+        let new_slot = Slot { key: key.clone(), value, prev: None, next: None };
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.slots[slot] = Some(new_slot);
+                slot
+            }
+            None => {
+                self.slots.push(Some(new_slot));
+                self.slots.len() - 1
+            }
+        };
+        self.table.insert(key, slot);
+        self.push_front(slot);
+        None
+    }
+
+    /// Removes `key` from the cache.
+    ///
+    /// # Returns
+    ///
+    /// The value that was associated with `key`, if any.
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let slot = self.table.remove(key)?;
+        Some(self.release(slot).1)
+    }
+
+    /// Removes the least recently used entry from the cache.
+    ///
+    /// # Returns
+    ///
+    /// Its key and value, or `None` if the cache is empty.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let slot = self.tail?;
+        let key = &self.slots[slot].as_ref().expect("the tail slot is occupied").key;
+        self.table.remove(key);
+        Some(self.release(slot))
+    }
+
+    /// Returns the least recently used entry, without changing how recently it was used.
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        let slot = self.slot(self.tail?);
+        Some((&slot.key, &slot.value))
+    }
+
+    /// Removes every entry from the cache. The hit and miss counters are kept.
+    pub fn clear(&mut self) {
+        self.table.clear();
+        self.slots.clear();
+        self.free.clear();
+        self.head = None;
+        self.tail = None;
+    }
+
+    /// Looks `key` up, counting a hit or a miss, and makes it the most recently used entry.
+    ///
+    /// # Returns
+    ///
+    /// The slot of `key`, if it is in the cache.
+    fn touch<Q>(&mut self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.table.get(key) {
+            Some(&slot) => {
+                self.hits += 1;
+                self.detach(slot);
+                self.push_front(slot);
+                Some(slot)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+}
+
+impl<K, V, S> LruCache<K, V, S> {
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    /// Checks whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of entries the cache holds.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of `get` and `get_mut` calls that found their key.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of `get` and `get_mut` calls that didn't find their key.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Returns the share of lookups that were hits, between `0.0` and `1.0`, or `None` if
+    /// nothing has been looked up yet.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
+
+    /// Sets the hit and miss counters back to zero.
+    pub fn reset_stats(&mut self) {
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Returns an iterator over the entries of the cache, from the most recently used to the
+    /// least recently used one.
+    pub fn iter(&self) -> Iter<'_, K, V, S> {
+        Iter { cache: self, next: self.head, remaining: self.len() }
+    }
+
+    fn slot(&self, slot: usize) -> &Slot<K, V> {
+        self.slots[slot].as_ref().expect("links only point to occupied slots")
+    }
+
+    fn slot_mut(&mut self, slot: usize) -> &mut Slot<K, V> {
+        self.slots[slot].as_mut().expect("links only point to occupied slots")
+    }
+
+    /// Unlinks `slot` from the list, leaving it in place.
+    fn detach(&mut self, slot: usize) {
+        let (prev, next) = {
+            let slot = self.slot(slot);
+            (slot.prev, slot.next)
+        };
+        match prev {
+            Some(prev) => self.slot_mut(prev).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.slot_mut(next).prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    /// Links the detached `slot` at the front of the list.
+    fn push_front(&mut self, slot: usize) {
+        let head = self.head;
+        {
+            let slot = self.slot_mut(slot);
+            slot.prev = None;
+            slot.next = head;
+        }
+        match head {
+            Some(head) => self.slot_mut(head).prev = Some(slot),
+            None => self.tail = Some(slot),
+        }
+        self.head = Some(slot);
+    }
+
+    /// Unlinks `slot`, whose key has already been removed from the table, and empties it.
+    fn release(&mut self, slot: usize) -> (K, V) {
+        self.detach(slot);
+        let Slot { key, value, .. } = self.slots[slot].take().expect("the slot is occupied");
+        self.free.push(slot);
+        (key, value)
+    }
+}
+
+impl<K: Debug, V: Debug, S> Debug for LruCache<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a LruCache<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, S>;
+
+    fn into_iter(self) -> Iter<'a, K, V, S> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of an `LruCache`, from the most recently used to the least
+/// recently used one.
+///
+/// Created by `LruCache::iter`.
+pub struct Iter<'a, K, V, S> {
+    cache: &'a LruCache<K, V, S>,
+    next: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, K, V, S> Iterator for Iter<'a, K, V, S> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let slot = self.cache.slot(self.next?);
+        self.next = slot.next;
+        self.remaining -= 1;
+        Some((&slot.key, &slot.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V, S> ExactSizeIterator for Iter<'_, K, V, S> {}
+
+impl<K, V, S> FusedIterator for Iter<'_, K, V, S> {}
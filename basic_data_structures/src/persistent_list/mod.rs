@@ -0,0 +1,196 @@
+use std::{
+    fmt::{self, Debug, Display},
+    iter::FusedIterator,
+    sync::Arc,
+};
+
+struct Node<T> {
+    data: T,
+    next: Link<T>,
+}
+
+type Link<T> = Option<Arc<Node<T>>>;
+
+/// An immutable singly linked list whose versions share their common tails.
+///
+/// Operations never modify a list: `cons` returns a new list whose tail *is* the old one, and
+/// `tail` returns the list that a head was consed onto. Both are O(1) and neither copies or
+/// clones any value, so keeping every older version around is cheap. Nodes are reference
+/// counted with `Arc`, so versions can also be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::persistent_list::PersistentList;
+///
+/// let empty = PersistentList::new();
+/// let one = empty.cons(1);
+/// let two = one.cons(2);
+/// assert_eq!(two.head(), Some(&2));
+/// assert_eq!(two.tail().head(), Some(&1));
+/// assert_eq!(one.len(), 1);
+/// assert!(empty.is_empty());
+/// ```
+pub struct PersistentList<T> {
+    head: Link<T>,
+    size: usize,
+}
+
+impl<T> PersistentList<T> {
+    /// Creates a new, empty list.
+    pub fn new() -> Self {
+        PersistentList { head: None, size: 0 }
+    }
+
+    /// Returns a new list with `value` in front of this one. This list is left untouched.
+    pub fn cons(&self, value: T) -> Self {
+        PersistentList {
+            head: Some(Arc::new(Node { data: value, next: self.head.clone() })),
+            size: self.size + 1,
+        }
+    }
+
+    /// Returns a reference to the first value of the list, or `None` if it is empty.
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_deref().map(|node| &node.data)
+    }
+
+    /// Returns the list without its first value. The tail of an empty list is empty.
+    pub fn tail(&self) -> Self {
+        match self.head.as_deref() {
+            Some(node) => PersistentList { head: node.next.clone(), size: self.size - 1 },
+            None => PersistentList::new(),
+        }
+    }
+
+    /// Splits the list into its first value and its tail, or returns `None` if it is empty.
+    pub fn uncons(&self) -> Option<(&T, Self)> {
+        let node = self.head.as_deref()?;
+        Some((&node.data, PersistentList { head: node.next.clone(), size: self.size - 1 }))
+    }
+
+    /// Returns the number of values in the list.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Checks whether the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns `true` if both lists are the very same version, sharing all of their nodes.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns an iterator over references to the values of the list, from head to tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref(), remaining: self.size }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for PersistentList<T> {
+    /// Returns another handle to the same version of the list, in O(1).
+    fn clone(&self) -> Self {
+        PersistentList { head: self.head.clone(), size: self.size }
+    }
+}
+
+impl<T> Drop for PersistentList<T> {
+    /// Frees the nodes only this list owns, one by one, so dropping a long list can't overflow
+    /// the stack. It stops at the first node another version still holds on to.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(node) = current {
+            match Arc::try_unwrap(node) {
+                Ok(mut node) => current = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && (self.ptr_eq(other) || self.iter().eq(other.iter()))
+    }
+}
+
+impl<T: Eq> Eq for PersistentList<T> {}
+
+impl<T: Debug> Debug for PersistentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Display> Display for PersistentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = String::new();
+        for value in self.iter() {
+            text = format!("{} -> {}", text, value);
+        }
+
+        Display::fmt(&text, f)
+    }
+}
+
+impl<T> FromIterator<T> for PersistentList<T> {
+    /// Builds a list whose values are in the same order as the ones of `iter`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: Vec<T> = iter.into_iter().collect();
+        let mut list = PersistentList::new();
+        for value in values.into_iter().rev() {
+            list = list.cons(value);
+        }
+        list
+    }
+}
+
+/// An iterator over references to the values of a `PersistentList`, from head to tail.
+///
+/// Created by `PersistentList::iter`.
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            self.remaining -= 1;
+            &node.data
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a PersistentList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
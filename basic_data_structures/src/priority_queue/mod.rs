@@ -0,0 +1,215 @@
+use crate::queue::Queue;
+
+/// Which end of the ordering a `PriorityQueue` pops first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeapKind {
+    /// The smallest value has the highest priority.
+    Min,
+    /// The biggest value has the highest priority.
+    Max,
+}
+
+/// A priority queue backed by a binary heap stored in a `Vec`.
+///
+/// `push` and `pop` are O(log n) and `peek` is O(1). Building a queue from a `Vec` or an
+/// iterator heapifies it in O(n).
+///
+/// It implements `Queue`, so it can be used as a `Box<dyn Queue<T>>`: `append` pushes a value
+/// and `pop` returns the value with the highest priority instead of the oldest one.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::priority_queue::{HeapKind, PriorityQueue};
+///
+/// let mut queue = PriorityQueue::from_vec(vec![5, 1, 3], HeapKind::Min);
+/// queue.push(0);
+/// assert_eq!(queue.pop(), Some(0));
+/// assert_eq!(queue.peek(), Some(&1));
+/// ```
+#[derive(Clone)]
+pub struct PriorityQueue<T> {
+    heap: Vec<T>,
+    kind: HeapKind,
+}
+
+impl<T: Ord> PriorityQueue<T> {
+    /// Creates a new, empty priority queue of the given kind.
+    pub fn new(kind: HeapKind) -> Self {
+        PriorityQueue { heap: Vec::new(), kind }
+    }
+
+    /// Creates a new, empty priority queue that pops the smallest value first.
+    pub fn new_min() -> Self {
+        Self::new(HeapKind::Min)
+    }
+
+    /// Creates a new, empty priority queue that pops the biggest value first.
+    pub fn new_max() -> Self {
+        Self::new(HeapKind::Max)
+    }
+
+    /// Creates a new, empty priority queue that can hold `capacity` values before it has to grow.
+    pub fn with_capacity(capacity: usize, kind: HeapKind) -> Self {
+        PriorityQueue { heap: Vec::with_capacity(capacity), kind }
+    }
+
+    /// Turns `values` into a priority queue in O(n), reusing its allocation.
+    pub fn from_vec(values: Vec<T>, kind: HeapKind) -> Self {
+        let mut queue = PriorityQueue { heap: values, kind };
+        let len = queue.heap.len();
+        for index in (0..len / 2).rev() {
+            queue.sift_down(index, len);
+        }
+        queue
+    }
+
+    /// Builds a priority queue of the given kind out of every value of `iter`, in O(n).
+    pub fn from_iter_with_kind<I: IntoIterator<Item = T>>(iter: I, kind: HeapKind) -> Self {
+        Self::from_vec(iter.into_iter().collect(), kind)
+    }
+
+    /// Adds `value` to the queue.
+    pub fn push(&mut self, value: T) {
+        self.heap.push(value);
+        self.sift_up(self.heap.len() - 1);
+    }
+
+    /// Removes and returns the value with the highest priority, or `None` if the queue is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.heap.swap(0, last);
+        let value = self.heap.pop();
+        self.sift_down(0, self.heap.len());
+        value
+    }
+
+    /// Returns a reference to the value with the highest priority, without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.first()
+    }
+
+    /// Returns the kind of the queue.
+    pub fn kind(&self) -> HeapKind {
+        self.kind
+    }
+
+    /// Returns the number of values in the queue.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Checks whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Removes every value from the queue.
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+
+    /// Returns an iterator over the values of the queue, in no particular order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.heap.iter()
+    }
+
+    /// Returns the values of the queue as a slice, in heap order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.heap
+    }
+
+    /// Consumes the queue and returns its values in the order `pop` would have returned them:
+    /// ascending for a min-heap and descending for a max-heap.
+    ///
+    /// The values are sorted in place with heapsort, so no extra memory is needed.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut end = self.heap.len();
+        while end > 1 {
+            end -= 1;
+            self.heap.swap(0, end);
+            self.sift_down(0, end);
+        }
+        self.heap.reverse();
+        self.heap
+    }
+
+    /// Consumes the queue and returns its values in no particular order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.heap
+    }
+
+    /// Returns `true` if `a` should be popped before `b`.
+    fn has_priority(&self, a: &T, b: &T) -> bool {
+        match self.kind {
+            HeapKind::Min => a < b,
+            HeapKind::Max => a > b,
+        }
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !self.has_priority(&self.heap[index], &self.heap[parent]) {
+                break;
+            }
+            self.heap.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    /// Moves the value at `index` down until the heap property holds again for the first
+    /// `end` values.
+    fn sift_down(&mut self, mut index: usize, end: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut highest = index;
+            if left < end && self.has_priority(&self.heap[left], &self.heap[highest]) {
+                highest = left;
+            }
+            if right < end && self.has_priority(&self.heap[right], &self.heap[highest]) {
+                highest = right;
+            }
+            if highest == index {
+                return;
+            }
+            self.heap.swap(index, highest);
+            index = highest;
+        }
+    }
+}
+
+impl<T: Ord> Queue<T> for PriorityQueue<T> {
+    fn append(&mut self, value: T) {
+        self.push(value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        PriorityQueue::pop(self)
+    }
+
+    fn size(&self) -> u32 {
+        self.heap.len() as u32
+    }
+}
+
+impl<T: Ord> FromIterator<T> for PriorityQueue<T> {
+    /// Builds a max-heap, like `std::collections::BinaryHeap`. Use `from_iter_with_kind` to pick
+    /// the kind.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_iter_with_kind(iter, HeapKind::Max)
+    }
+}
+
+impl<T: Ord> Extend<T> for PriorityQueue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
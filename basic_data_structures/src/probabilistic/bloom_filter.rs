@@ -0,0 +1,190 @@
+use std::{
+    f64::consts::LN_2,
+    fmt::{self, Debug},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+use super::{hash, random_seed};
+
+/// A set that answers "maybe present" or "definitely absent" using a fixed array of bits.
+///
+/// Inserting a value sets the bit chosen by each of `k` hash functions; a value may be in the
+/// set only if all of its bits are set. Values are never stored, so the filter takes the same
+/// amount of memory whatever they are, but it can't list them or remove them, and it answers
+/// `true` for some values that were never inserted: these are false positives. There are no
+/// false negatives.
+///
+/// The number of bits and of hash functions are picked from the number of values you expect
+/// to insert and the false positive rate you are ready to accept. Inserting more values than
+/// expected makes false positives more likely.
+///
+/// # Type parameters
+///
+/// * `T`: The type of the values, which must implement `Hash`. It can be unsized, like `str`.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::probabilistic::BloomFilter;
+///
+/// let mut seen = BloomFilter::new(1_000, 0.01);
+/// seen.insert("https://nannou.cc");
+/// assert!(seen.contains("https://nannou.cc"));
+/// ```
+pub struct BloomFilter<T: ?Sized> {
+    bits: Vec<u64>,
+    bit_count: usize,
+    hash_count: u32,
+    seed: u64,
+    len: usize,
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T: Hash + ?Sized> BloomFilter<T> {
+    /// Creates a new, empty filter sized for `expected_items` values and a false positive rate
+    /// of `false_positive_rate`, with hash functions seeded from the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` is not strictly between 0 and 1.
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        Self::with_seed(expected_items, false_positive_rate, random_seed())
+    }
+
+    /// Creates a new, empty filter sized like `new` does, whose hash functions are picked by
+    /// `seed`. Filters with the same size and seed set the same bits for the same values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `false_positive_rate` is not strictly between 0 and 1.
+    pub fn with_seed(expected_items: usize, false_positive_rate: f64, seed: u64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "the false positive rate must be between 0 and 1, got {}",
+            false_positive_rate
+        );
+        let expected_items = expected_items.max(1) as f64;
+        // The optimal sizes: m = -n ln(p) / ln(2)^2 bits and k = m / n ln(2) hash functions.
+        let bit_count = (-expected_items * false_positive_rate.ln() / (LN_2 * LN_2)).ceil();
+        let hash_count = (bit_count / expected_items * LN_2).round().max(1.0);
+        Self::with_size(bit_count as usize, hash_count as u32, seed)
+    }
+
+    /// Creates a new, empty filter with `bit_count` bits and `hash_count` hash functions picked
+    /// by `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_count` or `hash_count` is zero.
+    pub fn with_size(bit_count: usize, hash_count: u32, seed: u64) -> Self {
+        assert!(bit_count > 0, "a Bloom filter needs at least one bit");
+        assert!(hash_count > 0, "a Bloom filter needs at least one hash function");
+        BloomFilter {
+            bits: vec![0; bit_count.div_ceil(64)],
+            bit_count,
+            hash_count,
+            seed,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Adds `value` to the filter.
+    ///
+    /// # Returns
+    ///
+    /// `true` if at least one of its bits was not set yet, which means `value` was definitely
+    /// not in the filter. `false` means it may already have been.
+    pub fn insert(&mut self, value: &T) -> bool {
+        let mut new = false;
+        for index in 0..self.hash_count {
+            let bit = self.bit(index, value);
+            let (word, mask) = (bit / 64, 1 << (bit % 64));
+            new |= self.bits[word] & mask == 0;
+            self.bits[word] |= mask;
+        }
+        if new {
+            self.len += 1;
+        }
+        new
+    }
+
+    /// Returns `false` if `value` was definitely never inserted, and `true` if it may have
+    /// been.
+    pub fn contains(&self, value: &T) -> bool {
+        (0..self.hash_count).all(|index| {
+            let bit = self.bit(index, value);
+            self.bits[bit / 64] & (1 << (bit % 64)) != 0
+        })
+    }
+
+    /// Returns the number of insertions that changed the filter, which is the number of
+    /// distinct values inserted minus the ones that were mistaken for values already in it.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether nothing has been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Resets every bit, emptying the filter.
+    pub fn clear(&mut self) {
+        self.bits.iter_mut().for_each(|word| *word = 0);
+        self.len = 0;
+    }
+
+    /// Returns the number of bits of the filter.
+    pub fn bit_count(&self) -> usize {
+        self.bit_count
+    }
+
+    /// Returns the number of hash functions, which is the number of bits set per value.
+    pub fn hash_count(&self) -> u32 {
+        self.hash_count
+    }
+
+    /// Returns the seed that picks the hash functions.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Estimates the current false positive rate from the share of bits that are set: a value
+    /// that was never inserted is a false positive if all of its bits happen to be set.
+    pub fn false_positive_rate(&self) -> f64 {
+        let set: u32 = self.bits.iter().map(|word| word.count_ones()).sum();
+        (set as f64 / self.bit_count as f64).powi(self.hash_count as i32)
+    }
+
+    /// Returns the bit the `index`-th hash function picks for `value`.
+    fn bit(&self, index: u32, value: &T) -> usize {
+        (hash(self.seed, index as u64, value) % self.bit_count as u64) as usize
+    }
+}
+
+impl<T: ?Sized> Clone for BloomFilter<T> {
+    fn clone(&self) -> Self {
+        BloomFilter {
+            bits: self.bits.clone(),
+            bit_count: self.bit_count,
+            hash_count: self.hash_count,
+            seed: self.seed,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Debug for BloomFilter<T> {
+    /// Shows the sizes and seed of the filter, not its bits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BloomFilter")
+            .field("bit_count", &self.bit_count)
+            .field("hash_count", &self.hash_count)
+            .field("seed", &self.seed)
+            .field("len", &self.len)
+            .finish()
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    f64::consts::E,
+    fmt::{self, Debug},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+use super::{hash, random_seed};
+
+/// Estimates how many times each value has been added to a stream, in a fixed amount of memory.
+///
+/// The sketch is a grid of counters with `depth` rows of `width` columns, and each row has its
+/// own hash function. Adding a value increments the counter its hash picks in every row, and
+/// the estimate of a value is the smallest of its counters. Other values that land on the same
+/// counters can only make it bigger, so an estimate is never below the true count; with
+/// probability `1 - delta`, it is at most `epsilon * total` above it, where `total` is the sum
+/// of every count added.
+///
+/// # Type parameters
+///
+/// * `T`: The type of the values, which must implement `Hash`. It can be unsized, like `str`.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::probabilistic::CountMinSketch;
+///
+/// let mut words = CountMinSketch::new(0.01, 0.01);
+/// for word in "the cat and the hat and the bat".split(' ') {
+///     words.increment(word);
+/// }
+/// assert!(words.estimate("the") >= 3);
+/// assert_eq!(words.total(), 8);
+/// ```
+pub struct CountMinSketch<T: ?Sized> {
+    /// The `depth` rows of `width` counters, one after the other.
+    counters: Vec<u64>,
+    width: usize,
+    depth: usize,
+    seed: u64,
+    total: u64,
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T: Hash + ?Sized> CountMinSketch<T> {
+    /// Creates a new, empty sketch whose estimates are at most `epsilon * total` too big with
+    /// probability `1 - delta`, with hash functions seeded from the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `epsilon` or `delta` is not strictly between 0 and 1.
+    pub fn new(epsilon: f64, delta: f64) -> Self {
+        Self::with_seed(epsilon, delta, random_seed())
+    }
+
+    /// Creates a new, empty sketch sized like `new` does, whose hash functions are picked by
+    /// `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `epsilon` or `delta` is not strictly between 0 and 1.
+    pub fn with_seed(epsilon: f64, delta: f64, seed: u64) -> Self {
+        assert!(epsilon > 0.0 && epsilon < 1.0, "epsilon must be between 0 and 1, got {}", epsilon);
+        assert!(delta > 0.0 && delta < 1.0, "delta must be between 0 and 1, got {}", delta);
+        // w = e / epsilon columns bound the expected error of a row, and d = ln(1 / delta) rows
+        // make it unlikely that every row is over that bound.
+        let width = (E / epsilon).ceil() as usize;
+        let depth = (1.0 / delta).ln().ceil().max(1.0) as usize;
+        Self::with_size(width, depth, seed)
+    }
+
+    /// Creates a new, empty sketch with `depth` rows of `width` counters, whose hash functions
+    /// are picked by `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `depth` is zero.
+    pub fn with_size(width: usize, depth: usize, seed: u64) -> Self {
+        assert!(width > 0 && depth > 0, "a count-min sketch needs at least one counter");
+        CountMinSketch {
+            counters: vec![0; width * depth],
+            width,
+            depth,
+            seed,
+            total: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Adds `count` occurrences of `value`.
+    pub fn add(&mut self, value: &T, count: u64) {
+        for row in 0..self.depth {
+            let counter = self.counter(row, value);
+            self.counters[counter] = self.counters[counter].saturating_add(count);
+        }
+        self.total = self.total.saturating_add(count);
+    }
+
+    /// Adds one occurrence of `value`.
+    pub fn increment(&mut self, value: &T) {
+        self.add(value, 1);
+    }
+
+    /// Returns an estimate of the number of occurrences of `value`, which is never below the
+    /// true count.
+    pub fn estimate(&self, value: &T) -> u64 {
+        (0..self.depth)
+            .map(|row| self.counters[self.counter(row, value)])
+            .min()
+            .expect("a sketch has at least one row")
+    }
+
+    /// Returns the sum of every count added.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the number of counters in each row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows, which is the number of hash functions.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the seed that picks the hash functions.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Resets every counter.
+    pub fn clear(&mut self) {
+        self.counters.iter_mut().for_each(|counter| *counter = 0);
+        self.total = 0;
+    }
+
+    /// Adds the counts of `other` to this sketch, as if every value added to `other` had been
+    /// added here too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sketches don't have the same size and seed, since their counters would
+    /// not match.
+    pub fn merge(&mut self, other: &Self) {
+        assert!(
+            self.width == other.width && self.depth == other.depth && self.seed == other.seed,
+            "only sketches with the same size and seed can be merged"
+        );
+        for (counter, other) in self.counters.iter_mut().zip(&other.counters) {
+            *counter = counter.saturating_add(*other);
+        }
+        self.total = self.total.saturating_add(other.total);
+    }
+
+    /// Returns the index in `counters` of the counter `row` picks for `value`.
+    fn counter(&self, row: usize, value: &T) -> usize {
+        let column = hash(self.seed, row as u64, value) % self.width as u64;
+        row * self.width + column as usize
+    }
+}
+
+impl<T: ?Sized> Clone for CountMinSketch<T> {
+    fn clone(&self) -> Self {
+        CountMinSketch {
+            counters: self.counters.clone(),
+            width: self.width,
+            depth: self.depth,
+            seed: self.seed,
+            total: self.total,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Debug for CountMinSketch<T> {
+    /// Shows the sizes, seed and total of the sketch, not its counters.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CountMinSketch")
+            .field("width", &self.width)
+            .field("depth", &self.depth)
+            .field("seed", &self.seed)
+            .field("total", &self.total)
+            .finish()
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    error::Error,
+    fmt::{self, Debug},
+    hash::Hash,
+    marker::PhantomData,
+    ops::RangeInclusive,
+};
+
+use super::{hash, random_seed};
+
+/// The precisions a `HyperLogLog` accepts: from 16 registers up to 262 144.
+pub const PRECISIONS: RangeInclusive<u8> = 4..=18;
+
+/// The number of bytes `HyperLogLog::to_bytes` writes before the registers: the precision and
+/// the seed.
+const HEADER_LEN: usize = 1 + 8;
+
+/// Estimates the number of distinct values added to it, in a fixed amount of memory.
+///
+/// Each value is hashed once; the first `precision` bits of the hash pick one of
+/// `2^precision` registers, and the register keeps the largest number of leading zeros (plus
+/// one) seen in the rest of the hashes that picked it. Seeing `k` leading zeros takes about
+/// `2^k` distinct values, so the registers together give an estimate whose standard error is
+/// about `1.04 / sqrt(2^precision)`. Adding the same value again never changes anything, and
+/// each register takes one byte, whatever the number of values.
+///
+/// Sketches with the same precision and seed can be merged, which gives the estimate for the
+/// union of what was added to both, and `to_bytes`/`from_bytes` store the registers to merge
+/// sketches built in different runs.
+///
+/// # Type parameters
+///
+/// * `T`: The type of the values, which must implement `Hash`. It can be unsized, like `str`.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::probabilistic::HyperLogLog;
+///
+/// let mut states = HyperLogLog::new(12);
+/// for step in 0..100_000u64 {
+///     states.add(&(step % 5_000));
+/// }
+/// let count = states.count();
+/// assert!(4_500 < count && count < 5_500);
+/// ```
+pub struct HyperLogLog<T: ?Sized> {
+    registers: Vec<u8>,
+    precision: u8,
+    seed: u64,
+    marker: PhantomData<fn(&T)>,
+}
+
+impl<T: Hash + ?Sized> HyperLogLog<T> {
+    /// Creates a new, empty sketch with `2^precision` registers, whose hash function is seeded
+    /// from the system.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is not in `PRECISIONS`.
+    pub fn new(precision: u8) -> Self {
+        Self::with_seed(precision, random_seed())
+    }
+
+    /// Creates a new, empty sketch with `2^precision` registers, whose hash function is picked
+    /// by `seed`. Only sketches with the same precision and seed can be merged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is not in `PRECISIONS`.
+    pub fn with_seed(precision: u8, seed: u64) -> Self {
+        assert!(
+            PRECISIONS.contains(&precision),
+            "the precision must be between {} and {}, got {}",
+            PRECISIONS.start(),
+            PRECISIONS.end(),
+            precision
+        );
+        HyperLogLog { registers: vec![0; 1 << precision], precision, seed, marker: PhantomData }
+    }
+
+    /// Creates a sketch from registers returned by `registers`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `precision` is not in `PRECISIONS`, if there are not `2^precision`
+    /// registers, or if a register is bigger than any sketch of that precision could make it.
+    pub fn from_registers(
+        precision: u8,
+        seed: u64,
+        registers: Vec<u8>,
+    ) -> Result<Self, InvalidSketch> {
+        if !PRECISIONS.contains(&precision) {
+            return Err(InvalidSketch::Precision(precision));
+        }
+        let expected = 1 << precision;
+        if registers.len() != expected {
+            return Err(InvalidSketch::RegisterCount { expected, found: registers.len() });
+        }
+        let max_rank = Self::max_rank(precision);
+        if let Some(&register) = registers.iter().find(|&&register| register > max_rank) {
+            return Err(InvalidSketch::Register(register));
+        }
+        Ok(HyperLogLog { registers, precision, seed, marker: PhantomData })
+    }
+
+    /// Reads a sketch written by `to_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is too short to hold the precision and the seed, or if they
+    /// are not followed by valid registers, as `from_registers` checks them.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidSketch> {
+        if bytes.len() < HEADER_LEN {
+            return Err(InvalidSketch::Truncated);
+        }
+        let (header, registers) = bytes.split_at(HEADER_LEN);
+        let seed = u64::from_le_bytes(header[1..].try_into().expect("the seed takes 8 bytes"));
+        Self::from_registers(header[0], seed, registers.to_vec())
+    }
+
+    /// Writes the precision, the seed in little-endian order and then the registers, one byte
+    /// each.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.registers.len());
+        bytes.push(self.precision);
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+        bytes.extend_from_slice(&self.registers);
+        bytes
+    }
+
+    /// Adds `value` to the sketch.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a register changed, which means `value` was definitely not added before.
+    pub fn add(&mut self, value: &T) -> bool {
+        let hash = hash(self.seed, 0, value);
+        let index = (hash >> (64 - self.precision)) as usize;
+        // The bit set below the remaining 64 - precision bits bounds the count of zeros.
+        let rest = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns an estimate of the number of distinct values added.
+    pub fn count(&self) -> u64 {
+        let registers = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / registers),
+        };
+        let sum: f64 = self.registers.iter().map(|&register| 0.5f64.powi(register as i32)).sum();
+        let estimate = alpha * registers * registers / sum;
+
+        // With few values the raw estimate is biased, and counting the registers still at zero
+        // is more accurate. The 64-bit hash makes the correction for huge counts unnecessary.
+        let zeros = self.registers.iter().filter(|&&register| register == 0).count();
+        if estimate <= 2.5 * registers && zeros > 0 {
+            (registers * (registers / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+
+    /// Adds everything added to `other` to this sketch, so that `count` estimates the number of
+    /// distinct values added to either of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sketches don't have the same precision and seed, since their registers
+    /// would not match.
+    pub fn merge(&mut self, other: &Self) {
+        assert!(
+            self.precision == other.precision && self.seed == other.seed,
+            "only sketches with the same precision and seed can be merged"
+        );
+        for (register, &other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(other);
+        }
+    }
+
+    /// Checks whether nothing has been added.
+    pub fn is_empty(&self) -> bool {
+        self.registers.iter().all(|&register| register == 0)
+    }
+
+    /// Resets every register, emptying the sketch.
+    pub fn clear(&mut self) {
+        self.registers.iter_mut().for_each(|register| *register = 0);
+    }
+
+    /// Returns the number of bits of the hash that pick a register.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Returns the seed that picks the hash function.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the `2^precision` registers.
+    pub fn registers(&self) -> &[u8] {
+        &self.registers
+    }
+
+    /// Returns the standard error of `count` relative to the true number of distinct values.
+    pub fn relative_error(&self) -> f64 {
+        1.04 / (self.registers.len() as f64).sqrt()
+    }
+
+    /// Returns the largest value a register can hold with `precision`: every bit of the hash
+    /// left after the index is zero.
+    fn max_rank(precision: u8) -> u8 {
+        64 - precision + 1
+    }
+}
+
+impl<T: ?Sized> Clone for HyperLogLog<T> {
+    fn clone(&self) -> Self {
+        HyperLogLog {
+            registers: self.registers.clone(),
+            precision: self.precision,
+            seed: self.seed,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> PartialEq for HyperLogLog<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.precision == other.precision
+            && self.seed == other.seed
+            && self.registers == other.registers
+    }
+}
+
+impl<T: ?Sized> Eq for HyperLogLog<T> {}
+
+impl<T: ?Sized> Debug for HyperLogLog<T> {
+    /// Shows the precision and seed of the sketch, not its registers.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HyperLogLog")
+            .field("precision", &self.precision)
+            .field("seed", &self.seed)
+            .finish()
+    }
+}
+
+/// The error `HyperLogLog::from_bytes` and `HyperLogLog::from_registers` return when what they
+/// are given could not have come from a sketch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidSketch {
+    /// There are fewer bytes than the precision and the seed take.
+    Truncated,
+    /// The precision is not in `PRECISIONS`.
+    Precision(u8),
+    /// The number of registers does not match the precision.
+    RegisterCount { expected: usize, found: usize },
+    /// A register is bigger than the precision allows.
+    Register(u8),
+}
+
+impl fmt::Display for InvalidSketch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidSketch::Truncated => write!(f, "too few bytes for a HyperLogLog header"),
+            InvalidSketch::Precision(precision) => write!(
+                f,
+                "precision {} is not between {} and {}",
+                precision,
+                PRECISIONS.start(),
+                PRECISIONS.end()
+            ),
+            InvalidSketch::RegisterCount { expected, found } => {
+                write!(f, "expected {} registers, found {}", expected, found)
+            }
+            InvalidSketch::Register(register) => {
+                write!(f, "register value {} is too big for the precision", register)
+            }
+        }
+    }
+}
+
+impl Error for InvalidSketch {}
@@ -0,0 +1,27 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::hash_table::SipHash;
+
+pub mod bloom_filter;
+pub use bloom_filter::BloomFilter;
+pub mod count_min_sketch;
+pub use count_min_sketch::CountMinSketch;
+pub mod hyper_log_log;
+pub use hyper_log_log::HyperLogLog;
+
+/// Hashes `value` with the `index`-th function of the family picked by `seed`.
+///
+/// Every function is SipHash, the hasher `HashTable` uses by default, keyed with `seed` and
+/// `index`, so the functions are independent of each other and the same seed always gives the
+/// same family.
+fn hash<T: Hash + ?Sized>(seed: u64, index: u64, value: &T) -> u64 {
+    SipHash::with_keys(seed, index).hash_one(value)
+}
+
+/// Returns a seed that differs from one run to the next.
+fn random_seed() -> u64 {
+    RandomState::new().hash_one(0)
+}
@@ -0,0 +1,261 @@
+use std::iter::FusedIterator;
+
+use super::Queue;
+
+const MIN_CAPACITY: usize = 4;
+
+/// What a bounded `ArrayQueue` does when a value is appended while it is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The new value is discarded and the queue is left untouched.
+    Reject,
+    /// The oldest value is dropped to make room for the new one.
+    Overwrite,
+}
+
+/// A queue stored in a contiguous circular buffer.
+///
+/// Values live next to each other in a single allocation, so appending and popping are
+/// amortized O(1) and walking the queue is cache friendly. By default the buffer doubles its
+/// capacity when it fills up; a bounded queue keeps a fixed capacity and follows its
+/// `OverflowPolicy` instead.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::queue::{ArrayQueue, OverflowPolicy, Queue};
+///
+/// let mut queue = ArrayQueue::bounded(2, OverflowPolicy::Overwrite);
+/// queue.append(1);
+/// queue.append(2);
+/// queue.append(3);
+/// assert_eq!(queue.pop(), Some(2));
+/// ```
+pub struct ArrayQueue<T> {
+    buffer: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+    overflow: Option<OverflowPolicy>,
+}
+
+impl<T> ArrayQueue<T> {
+    /// Creates a new, empty queue that grows as needed. It doesn't allocate until the first
+    /// value is appended.
+    pub fn new() -> Self {
+        ArrayQueue { buffer: Vec::new(), head: 0, len: 0, overflow: None }
+    }
+
+    /// Creates a new, empty queue that can hold `capacity` values before it has to grow.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut queue = Self::new();
+        queue.buffer.resize_with(capacity, || None);
+        queue
+    }
+
+    /// Creates a new, empty queue that never holds more than `capacity` values.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of values in the queue.
+    /// * `overflow` - What `append` does once the queue is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    pub fn bounded(capacity: usize, overflow: OverflowPolicy) -> Self {
+        assert!(capacity > 0, "a bounded queue needs room for at least one value");
+        let mut queue = Self::with_capacity(capacity);
+        queue.overflow = Some(overflow);
+        queue
+    }
+
+    /// Appends `value` to the end of the queue, growing it if needed, but never overwriting
+    /// another value.
+    ///
+    /// # Returns
+    ///
+    /// `Err(value)` if the queue is bounded and full, whatever its `OverflowPolicy`.
+    pub fn try_append(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            if self.overflow.is_some() {
+                return Err(value);
+            }
+            self.grow();
+        }
+        let index = self.physical_index(self.len);
+        self.buffer[index] = Some(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns a reference to the first value in the queue, without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    /// Returns a mutable reference to the first value in the queue, without removing it.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.buffer[self.head].as_mut()
+    }
+
+    /// Returns the number of values in the queue.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Checks whether the queue has to grow, or apply its `OverflowPolicy`, to take another value.
+    pub fn is_full(&self) -> bool {
+        self.len == self.buffer.len()
+    }
+
+    /// Returns the number of values the queue can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the queue has a fixed capacity.
+    pub fn is_bounded(&self) -> bool {
+        self.overflow.is_some()
+    }
+
+    /// Removes every value from the queue, keeping its capacity.
+    pub fn clear(&mut self) {
+        for slot in self.buffer.iter_mut() {
+            *slot = None;
+        }
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the values of the queue, from the first to the last one.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { queue: self, offset: 0 }
+    }
+
+    fn physical_index(&self, offset: usize) -> usize {
+        (self.head + offset) % self.buffer.len()
+    }
+
+    /// Moves the values into a buffer twice as big, unwrapping them so the first one ends up
+    /// at the start of the new buffer.
+    fn grow(&mut self) {
+        let new_capacity = (self.buffer.len() * 2).max(MIN_CAPACITY);
+        let mut buffer = Vec::with_capacity(new_capacity);
+        for offset in 0..self.len {
+            let index = self.physical_index(offset);
+            buffer.push(self.buffer[index].take());
+        }
+        buffer.resize_with(new_capacity, || None);
+        self.buffer = buffer;
+        self.head = 0;
+    }
+}
+
+impl<T> Default for ArrayQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for ArrayQueue<T> {
+    fn clone(&self) -> Self {
+        ArrayQueue {
+            buffer: self.buffer.clone(),
+            head: self.head,
+            len: self.len,
+            overflow: self.overflow,
+        }
+    }
+}
+
+impl<T> Queue<T> for ArrayQueue<T> {
+    /// Appends `value` to the end of the queue.
+    ///
+    /// A full, bounded queue discards `value` if its policy is `OverflowPolicy::Reject` and
+    /// drops its first value if it is `OverflowPolicy::Overwrite`.
+    fn append(&mut self, value: T) {
+        if let Err(value) = self.try_append(value) {
+            if self.overflow == Some(OverflowPolicy::Overwrite) {
+                self.buffer[self.head] = Some(value);
+                self.head = self.physical_index(1);
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = self.buffer[self.head].take();
+        self.head = self.physical_index(1);
+        self.len -= 1;
+        value
+    }
+
+    fn size(&self) -> u32 {
+        self.len as u32
+    }
+}
+
+impl<T> FromIterator<T> for ArrayQueue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = ArrayQueue::new();
+        queue.extend(iter);
+        queue
+    }
+}
+
+impl<T> Extend<T> for ArrayQueue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.append(value);
+        }
+    }
+}
+
+/// An iterator over references to the values of an `ArrayQueue`, from the first to the last one.
+///
+/// Created by `ArrayQueue::iter`.
+pub struct Iter<'a, T> {
+    queue: &'a ArrayQueue<T>,
+    offset: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.offset == self.queue.len {
+            return None;
+        }
+        let index = self.queue.physical_index(self.offset);
+        self.offset += 1;
+        self.queue.buffer[index].as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.queue.len - self.offset;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a ArrayQueue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
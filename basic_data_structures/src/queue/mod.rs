@@ -1,5 +1,8 @@
 use crate::linked_list::{LinkedList, node::Node};
 
+pub mod array_queue;
+pub use array_queue::{ArrayQueue, OverflowPolicy};
+
 
 /// A generic trait for implementing a queue, which is a data structure that stores a collection
 /// of elements in a first-in, first-out (FIFO) order.
@@ -28,7 +31,7 @@ pub trait Queue<T> {
 }
 
 
-impl<T: Clone> Queue<T> for LinkedList<T> {
+impl<T> Queue<T> for LinkedList<T> {
     fn append(&mut self, value: T) {
         self.size += 1;
         let new_node = Some(Box::new(Node::new(value)));
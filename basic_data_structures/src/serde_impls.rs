@@ -0,0 +1,324 @@
+use std::{
+    fmt,
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+
+use serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    avl_tree::AvlTree,
+    doubly_linked_list::DoublyLinkedList,
+    graph::{Edge, Graph, GraphKind},
+    hash_set::HashSet,
+    hash_table::{HashTable, OpenHashTable},
+    linked_list::LinkedList,
+    persistent_list::PersistentList,
+    priority_queue::{HeapKind, PriorityQueue},
+    probabilistic::HyperLogLog,
+    queue::ArrayQueue,
+    skip_list::SkipList,
+    stack::ArrayStack,
+    trie::Trie,
+};
+
+/// Deserializes a sequence into any collection that can be built from an iterator, keeping
+/// the order of the values.
+struct SeqVisitor<C, T> {
+    collection: PhantomData<fn() -> C>,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<'de, C, T> Visitor<'de> for SeqVisitor<C, T>
+where
+    C: FromIterator<T>,
+    T: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(values.into_iter().collect())
+    }
+}
+
+fn deserialize_seq<'de, D, C, T>(deserializer: D) -> Result<C, D::Error>
+where
+    D: Deserializer<'de>,
+    C: FromIterator<T>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_seq(SeqVisitor { collection: PhantomData, value: PhantomData })
+}
+
+/// Deserializes a map into any collection that can be extended with key/value pairs.
+struct MapVisitor<C, K, V> {
+    collection: PhantomData<fn() -> C>,
+    entry: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'de, C, K, V> Visitor<'de> for MapVisitor<C, K, V>
+where
+    C: Default + Extend<(K, V)>,
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<C, A::Error> {
+        let mut collection = C::default();
+        while let Some(entry) = map.next_entry()? {
+            collection.extend(Some(entry));
+        }
+        Ok(collection)
+    }
+}
+
+fn deserialize_map<'de, D, C, K, V>(deserializer: D) -> Result<C, D::Error>
+where
+    D: Deserializer<'de>,
+    C: Default + Extend<(K, V)>,
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    deserializer.deserialize_map(MapVisitor { collection: PhantomData, entry: PhantomData })
+}
+
+/// Implements `Serialize` and `Deserialize` for a collection that is stored as a sequence of
+/// its values, from the first one to the last one.
+macro_rules! seq_impls {
+    ($collection:ident) => {
+        impl<T: Serialize> Serialize for $collection<T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.iter())
+            }
+        }
+
+        impl<'de, T: Deserialize<'de>> Deserialize<'de> for $collection<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_seq(deserializer)
+            }
+        }
+    };
+}
+
+seq_impls!(LinkedList);
+seq_impls!(DoublyLinkedList);
+seq_impls!(PersistentList);
+
+// A bounded queue comes back as a growable one: only its values are stored.
+seq_impls!(ArrayQueue);
+
+impl<T: Serialize> Serialize for ArrayStack<T> {
+    /// Stores the values from the bottom of the stack to the top, the order they have to be
+    /// pushed in to rebuild it.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().rev())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for ArrayStack<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_seq(deserializer)
+    }
+}
+
+#[derive(Serialize)]
+struct PriorityQueueRef<'a, T> {
+    kind: HeapKind,
+    values: &'a [T],
+}
+
+#[derive(Deserialize)]
+struct PriorityQueueData<T> {
+    kind: HeapKind,
+    values: Vec<T>,
+}
+
+impl<T: Serialize + Ord> Serialize for PriorityQueue<T> {
+    /// Stores the kind of the queue and its values in heap order.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PriorityQueueRef { kind: self.kind(), values: self.as_slice() }.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Ord> Deserialize<'de> for PriorityQueue<T> {
+    /// Heapifies the stored values again, so the input doesn't have to be a valid heap.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = PriorityQueueData::deserialize(deserializer)?;
+        Ok(PriorityQueue::from_vec(data.values, data.kind))
+    }
+}
+
+impl<K: Serialize, V: Serialize, H> Serialize for HashTable<K, V, H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, K, V, H> Deserialize<'de> for HashTable<K, V, H>
+where
+    K: Deserialize<'de> + Hash + Eq,
+    V: Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_map(deserializer)
+    }
+}
+
+impl<K: Serialize, V: Serialize, H> Serialize for OpenHashTable<K, V, H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, K, V, H> Deserialize<'de> for OpenHashTable<K, V, H>
+where
+    K: Deserialize<'de> + Hash + Eq,
+    V: Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_map(deserializer)
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for AvlTree<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, K: Deserialize<'de> + Ord, V: Deserialize<'de>> Deserialize<'de> for AvlTree<K, V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_map(deserializer)
+    }
+}
+
+impl<K: Serialize, V: Serialize> Serialize for SkipList<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, K: Deserialize<'de> + Ord, V: Deserialize<'de>> Deserialize<'de> for SkipList<K, V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_map(deserializer)
+    }
+}
+
+impl<V: Serialize> Serialize for Trie<V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for Trie<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_map::<D, Self, String, V>(deserializer)
+    }
+}
+
+#[derive(Serialize)]
+struct GraphRef<'a, N, E> {
+    kind: GraphKind,
+    nodes: Vec<&'a N>,
+    edges: &'a [Edge<E>],
+}
+
+#[derive(Deserialize)]
+struct GraphData<N, E> {
+    kind: GraphKind,
+    nodes: Vec<N>,
+    edges: Vec<Edge<E>>,
+}
+
+impl<N: Serialize, E: Serialize> Serialize for Graph<N, E> {
+    /// Stores the kind of the graph, the data of its nodes and its edges, so indices are kept.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let nodes = (0..self.node_count()).map(|node| &self[node]).collect();
+        GraphRef { kind: self.kind(), nodes, edges: self.edges() }.serialize(serializer)
+    }
+}
+
+impl<'de, N: Deserialize<'de>, E: Deserialize<'de>> Deserialize<'de> for Graph<N, E> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = GraphData::deserialize(deserializer)?;
+        let mut graph = Graph::new(data.kind);
+        for node in data.nodes {
+            graph.add_node(node);
+        }
+        for Edge { from, to, weight } in data.edges {
+            if from.max(to) >= graph.node_count() {
+                return Err(D::Error::custom(format!(
+                    "edge {} -> {} links a node that is not in the graph",
+                    from, to
+                )));
+            }
+            graph.add_edge(from, to, weight);
+        }
+        Ok(graph)
+    }
+}
+
+impl<T: Serialize + Hash + Eq, H: BuildHasher> Serialize for HashSet<T, H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T, H> Deserialize<'de> for HashSet<T, H>
+where
+    T: Deserialize<'de> + Hash + Eq,
+    H: BuildHasher + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_seq(deserializer)
+    }
+}
+
+#[derive(Serialize)]
+struct HyperLogLogRef<'a> {
+    precision: u8,
+    seed: u64,
+    registers: &'a [u8],
+}
+
+#[derive(Deserialize)]
+struct HyperLogLogData {
+    precision: u8,
+    seed: u64,
+    registers: Vec<u8>,
+}
+
+impl<T: Hash + ?Sized> Serialize for HyperLogLog<T> {
+    /// Stores the seed along with the registers, so a sketch read back can still be merged with
+    /// the ones it was built alongside.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (precision, seed) = (self.precision(), self.seed());
+        HyperLogLogRef { precision, seed, registers: self.registers() }.serialize(serializer)
+    }
+}
+
+impl<'de, T: Hash + ?Sized> Deserialize<'de> for HyperLogLog<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = HyperLogLogData::deserialize(deserializer)?;
+        HyperLogLog::from_registers(data.precision, data.seed, data.registers)
+            .map_err(D::Error::custom)
+    }
+}
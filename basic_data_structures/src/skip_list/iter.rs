@@ -0,0 +1,113 @@
+use std::iter::FusedIterator;
+
+use super::SkipList;
+
+/// An iterator over the entries of a `SkipList`, in ascending key order.
+///
+/// Created by `SkipList::iter`.
+pub struct Iter<'a, K, V> {
+    pub(super) list: &'a SkipList<K, V>,
+    pub(super) next: Option<usize>,
+    pub(super) remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let node = self.list.node(self.next?);
+        self.next = node.next[0];
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Iter { list: self.list, next: self.next, remaining: self.remaining }
+    }
+}
+
+/// An iterator over the keys of a `SkipList`, in ascending order.
+///
+/// Created by `SkipList::keys`.
+pub struct Keys<'a, K, V> {
+    pub(super) iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.iter.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+/// An iterator over the values of a `SkipList`, in ascending key order.
+///
+/// Created by `SkipList::values`.
+pub struct Values<'a, K, V> {
+    pub(super) iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        self.iter.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+/// An iterator over the entries of a `SkipList` whose keys are within a range, in ascending
+/// key order.
+///
+/// Created by `SkipList::range`.
+pub struct Range<'a, K, V> {
+    pub(super) list: &'a SkipList<K, V>,
+    pub(super) next: Option<usize>,
+    /// The index of the last node in the range.
+    pub(super) last: Option<usize>,
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let index = self.next?;
+        let node = self.list.node(index);
+        self.next = if Some(index) == self.last { None } else { node.next[0] };
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<K, V> FusedIterator for Range<'_, K, V> {}
+
+impl<K, V> Clone for Range<'_, K, V> {
+    fn clone(&self) -> Self {
+        Range { list: self.list, next: self.next, last: self.last }
+    }
+}
@@ -0,0 +1,380 @@
+use std::{
+    borrow::Borrow,
+    collections::hash_map::RandomState,
+    fmt::{self, Debug, Display},
+    hash::BuildHasher,
+    mem,
+    ops::RangeBounds,
+};
+
+use crate::bounds::{above, below};
+
+pub mod iter;
+use iter::{Iter, Keys, Range, Values};
+
+/// The maximum number of levels. With a promotion probability of one half, it is enough for
+/// far more entries than fit in memory.
+const MAX_LEVEL: usize = 32;
+
+/// The seed used instead of `0`, which would make xorshift return `0` forever.
+const FALLBACK_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+#[derive(Clone)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    /// The index of the next node on each level this node is part of, from the bottom up.
+    next: Vec<Option<usize>>,
+}
+
+/// An ordered map stored as a skip list.
+///
+/// Every entry is on the bottom level, a sorted singly linked list, and each level above skips
+/// over roughly half of the entries of the one below. Searches start on the top level and drop
+/// down whenever the next key is too big, so `insert`, `get` and `remove` take expected
+/// O(log n) steps. The level of a new entry is chosen at random: the generator can be seeded
+/// with `with_seed`, which makes the shape of the list reproducible.
+///
+/// Nodes are stored in a `Vec` and link to each other by index. Removed slots are reused by
+/// later insertions.
+///
+/// # Type parameters
+///
+/// * `K`: The type of the keys, which must implement `Ord`.
+/// * `V`: The type of the values.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::skip_list::SkipList;
+///
+/// let mut list = SkipList::with_seed(42);
+/// list.insert(3, "c");
+/// list.insert(1, "a");
+/// list.insert(2, "b");
+///
+/// assert_eq!(list.get(&2), Some(&"b"));
+/// let keys: Vec<_> = list.keys().copied().collect();
+/// assert_eq!(keys, vec![1, 2, 3]);
+/// let values: Vec<_> = list.range(2..).map(|(_, value)| *value).collect();
+/// assert_eq!(values, vec!["b", "c"]);
+/// ```
+#[derive(Clone)]
+pub struct SkipList<K, V> {
+    nodes: Vec<Option<Node<K, V>>>,
+    /// The slots of `nodes` left empty by removals.
+    free: Vec<usize>,
+    /// The first node of each level.
+    head: [Option<usize>; MAX_LEVEL],
+    /// The number of levels that have at least one node.
+    levels: usize,
+    size: usize,
+    rng: u64,
+}
+
+impl<K, V> SkipList<K, V> {
+    /// Creates a new, empty list whose random generator is seeded from the system.
+    pub fn new() -> Self {
+        Self::with_seed(RandomState::new().hash_one(0))
+    }
+
+    /// Creates a new, empty list whose random generator starts from `seed`. Two lists built
+    /// with the same seed and the same operations have the same shape.
+    pub fn with_seed(seed: u64) -> Self {
+        SkipList {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: [None; MAX_LEVEL],
+            levels: 0,
+            size: 0,
+            rng: if seed == 0 { FALLBACK_SEED } else { seed },
+        }
+    }
+
+    /// Returns the number of entries in the list.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Checks whether the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns the number of levels in use, `0` for an empty list.
+    pub fn levels(&self) -> usize {
+        self.levels
+    }
+
+    /// Removes every entry from the list. The random generator keeps its state.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.free.clear();
+        self.head = [None; MAX_LEVEL];
+        self.levels = 0;
+        self.size = 0;
+    }
+
+    /// Returns the entry with the smallest key.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        let node = self.node(self.head[0]?);
+        Some((&node.key, &node.value))
+    }
+
+    /// Returns the entry with the largest key.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        let mut current = None;
+        for level in (0..self.levels).rev() {
+            while let Some(next) = self.next(current, level) {
+                current = Some(next);
+            }
+        }
+        let node = self.node(current?);
+        Some((&node.key, &node.value))
+    }
+
+    /// Returns an iterator over the entries of the list, in ascending key order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { list: self, next: self.head[0], remaining: self.size }
+    }
+
+    /// Returns an iterator over the keys of the list, in ascending order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Returns an iterator over the values of the list, in ascending key order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+
+    fn node(&self, index: usize) -> &Node<K, V> {
+        self.nodes[index].as_ref().expect("links only point to occupied slots")
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut Node<K, V> {
+        self.nodes[index].as_mut().expect("links only point to occupied slots")
+    }
+
+    /// Returns the node after `current` on `level`, where `None` stands for the head.
+    fn next(&self, current: Option<usize>, level: usize) -> Option<usize> {
+        match current {
+            None => self.head[level],
+            Some(index) => self.node(index).next[level],
+        }
+    }
+
+    fn set_next(&mut self, current: Option<usize>, level: usize, next: Option<usize>) {
+        match current {
+            None => self.head[level] = next,
+            Some(index) => self.node_mut(index).next[level] = next,
+        }
+    }
+
+    /// Picks the number of levels of a new node: each extra level has a one in two chance.
+    fn random_level(&mut self) -> usize {
+        // xorshift64
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng.trailing_ones() as usize + 1).min(MAX_LEVEL)
+    }
+
+    /// Walks down the list and stops, on every level, at the last node for which `go_on`
+    /// returns `true`.
+    ///
+    /// # Returns
+    ///
+    /// The node reached on each level, where `None` stands for the head.
+    fn walk(&self, mut go_on: impl FnMut(&K) -> bool) -> [Option<usize>; MAX_LEVEL] {
+        let mut path = [None; MAX_LEVEL];
+        let mut current = None;
+        for level in (0..self.levels).rev() {
+            while let Some(next) = self.next(current, level) {
+                if !go_on(&self.node(next).key) {
+                    break;
+                }
+                current = Some(next);
+            }
+            path[level] = current;
+        }
+        path
+    }
+}
+
+impl<K: Ord, V> SkipList<K, V> {
+    /// Inserts a key/value pair into the list.
+    ///
+    /// # Returns
+    ///
+    /// The previous value of `key`, if it was already in the list. The key itself is not
+    /// updated.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let path = self.walk(|other| *other < key);
+        if let Some(index) = self.next(path[0], 0) {
+            let node = self.node_mut(index);
+            if node.key == key {
+                return Some(mem::replace(&mut node.value, value));
+            }
+        }
+
+        let level = self.random_level();
+        // The new levels start at the head, which `path` already holds as `None`.
+        self.levels = self.levels.max(level);
+        let next = (0..level).map(|l| self.next(path[l], l)).collect();
+        let node = Node { key, value, next };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = Some(node);
+                index
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+        for (l, previous) in path.iter().enumerate().take(level) {
+            self.set_next(*previous, l, Some(index));
+        }
+        self.size += 1;
+        None
+    }
+
+    /// Removes `key` from the list.
+    ///
+    /// # Returns
+    ///
+    /// The value that was associated with `key`, if any.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let path = self.walk(|other| other.borrow() < key);
+        let index = self.next(path[0], 0)?;
+        if self.node(index).key.borrow() != key {
+            return None;
+        }
+
+        let node = self.nodes[index].take().expect("links only point to occupied slots");
+        for (l, next) in node.next.iter().enumerate() {
+            self.set_next(path[l], l, *next);
+        }
+        while self.levels > 0 && self.head[self.levels - 1].is_none() {
+            self.levels -= 1;
+        }
+        self.free.push(index);
+        self.size -= 1;
+        Some(node.value)
+    }
+
+    /// Returns a reference to the value associated with `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(&self.node(index).value)
+    }
+
+    /// Returns a mutable reference to the value associated with `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(&mut self.node_mut(index).value)
+    }
+
+    /// Returns `true` if the list contains `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Returns an iterator over the entries whose keys are within `range`, in ascending key
+    /// order.
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let first = self.next(self.walk(|key| !above(key.borrow(), range.start_bound()))[0], 0);
+        let last = self.walk(|key| below(key.borrow(), range.end_bound()))[0];
+        match (first, last) {
+            (Some(first), Some(last)) if self.node(first).key <= self.node(last).key => {
+                Range { list: self, next: Some(first), last: Some(last) }
+            }
+            _ => Range { list: self, next: None, last: None },
+        }
+    }
+
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.next(self.walk(|other| other.borrow() < key)[0], 0)?;
+        (self.node(index).key.borrow() == key).then_some(index)
+    }
+}
+
+impl<K, V> Default for SkipList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for SkipList<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Display, V> SkipList<K, V> {
+    /// Prints the keys of every level, from the top one down to the bottom one, which holds
+    /// every key.
+    pub fn print(&self) {
+        for level in (0..self.levels).rev() {
+            let mut line = format!("L{}:", level);
+            let mut current = self.head[level];
+            while let Some(index) = current {
+                let node = self.node(index);
+                line.push_str(&format!(" -> {}", node.key));
+                current = node.next[level];
+            }
+            println!("{}", line);
+        }
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SkipList<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for SkipList<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a SkipList<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
@@ -0,0 +1,125 @@
+use std::{iter::Rev, slice::Iter};
+
+use super::Stack;
+
+/// A stack stored in a contiguous, growable buffer.
+///
+/// The top of the stack is the end of the buffer, so pushing and popping are amortized O(1)
+/// and never allocate once the stack has reached its working size.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::stack::{ArrayStack, Stack};
+///
+/// let mut stack = ArrayStack::with_capacity(8);
+/// stack.prepend(1);
+/// stack.prepend(2);
+/// assert_eq!(stack.peek(), Some(&2));
+/// assert_eq!(stack.pop(), Some(2));
+/// ```
+#[derive(Clone)]
+pub struct ArrayStack<T> {
+    values: Vec<T>,
+}
+
+impl<T> ArrayStack<T> {
+    /// Creates a new, empty stack. It doesn't allocate until the first value is pushed.
+    pub fn new() -> Self {
+        ArrayStack { values: Vec::new() }
+    }
+
+    /// Creates a new, empty stack that can hold `capacity` values before it has to grow.
+    pub fn with_capacity(capacity: usize) -> Self {
+        ArrayStack { values: Vec::with_capacity(capacity) }
+    }
+
+    /// Returns the number of values the stack can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
+
+    /// Makes room for at least `additional` more values without growing again.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+    }
+
+    /// Shrinks the capacity of the stack as close as possible to the number of values in it.
+    pub fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+    }
+
+    /// Returns the number of values in the stack.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Checks whether the stack is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns an iterator over the values of the stack, from the top to the bottom.
+    pub fn iter(&self) -> Rev<Iter<'_, T>> {
+        self.values.iter().rev()
+    }
+}
+
+impl<T> Default for ArrayStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Stack<T> for ArrayStack<T> {
+    fn prepend(&mut self, value: T) {
+        self.values.push(value);
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.values.pop()
+    }
+
+    fn size(&self) -> u32 {
+        self.values.len() as u32
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.values.last()
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.values.last_mut()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl<T> FromIterator<T> for ArrayStack<T> {
+    /// Pushes every value of `iter` in order, so the last one ends up on top.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        ArrayStack { values: Vec::from_iter(iter) }
+    }
+}
+
+impl<T> Extend<T> for ArrayStack<T> {
+    /// Pushes every value of `iter` in order, so the last one ends up on top.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.values.extend(iter);
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArrayStack<T> {
+    type Item = &'a T;
+    type IntoIter = Rev<Iter<'a, T>>;
+
+    fn into_iter(self) -> Rev<Iter<'a, T>> {
+        self.iter()
+    }
+}
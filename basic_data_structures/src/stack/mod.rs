@@ -1,5 +1,8 @@
 use crate::linked_list::{LinkedList, node::Node};
 
+pub mod array_stack;
+pub use array_stack::ArrayStack;
+
 
 /// A trait representing a stack data structure that can store values of type `T`.
 pub trait Stack<T> {
@@ -25,9 +28,38 @@ pub trait Stack<T> {
     ///
     /// The number of values currently in the stack.
     fn size(&self) -> u32;
+
+    /// Returns a reference to the top value of the stack, without removing it.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&T>` that contains the top value of the stack, or `None` if the stack is empty.
+    fn peek(&self) -> Option<&T>;
+
+    /// Returns a mutable reference to the top value of the stack, without removing it.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<&mut T>` that contains the top value of the stack, or `None` if the stack is
+    /// empty.
+    fn peek_mut(&mut self) -> Option<&mut T>;
+
+    /// Checks whether the stack is empty.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the stack holds no values, `false` otherwise.
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Removes every value from the stack.
+    fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
 }
 
-impl<T: Clone> Stack<T> for LinkedList<T> {
+impl<T> Stack<T> for LinkedList<T> {
     fn prepend(&mut self, value: T) {
         let mut new_node = Box::new(Node::new(value));
         new_node.next = self.head.take();
@@ -48,4 +80,12 @@ impl<T: Clone> Stack<T> for LinkedList<T> {
     fn size(&self) -> u32 {
         self.size as u32
     }
+
+    fn peek(&self) -> Option<&T> {
+        self.head.as_deref().map(|node| &*node.data)
+    }
+
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_deref_mut().map(|node| &mut *node.data)
+    }
 }
\ No newline at end of file
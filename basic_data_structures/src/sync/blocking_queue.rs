@@ -0,0 +1,226 @@
+use std::{
+    fmt,
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use crate::queue::{ArrayQueue, OverflowPolicy, Queue};
+
+/// The error returned when a value can't be pushed into a `BlockingQueue`. It hands the value
+/// back to the caller.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PushError<T> {
+    /// The queue was full. Only returned by `try_push`.
+    Full(T),
+    /// The queue was closed.
+    Closed(T),
+}
+
+impl<T> PushError<T> {
+    /// Returns the value that couldn't be pushed.
+    pub fn into_inner(self) -> T {
+        match self {
+            PushError::Full(value) | PushError::Closed(value) => value,
+        }
+    }
+}
+
+impl<T> fmt::Display for PushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PushError::Full(_) => "the queue is full".fmt(f),
+            PushError::Closed(_) => "the queue is closed".fmt(f),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for PushError<T> {}
+
+struct State<T> {
+    values: ArrayQueue<T>,
+    closed: bool,
+}
+
+/// A bounded FIFO queue that can be shared between threads, typically behind an `Arc`.
+///
+/// Producers block in `push` while the queue is full and consumers block in `pop` while it is
+/// empty. Closing the queue wakes every waiting thread: pushes start failing right away, while
+/// pops keep returning the values that are left and then return `None`.
+///
+/// # Examples
+///
+/// ```
+/// use std::{sync::Arc, thread};
+/// use basic_data_structures::sync::BlockingQueue;
+///
+/// let queue = Arc::new(BlockingQueue::new(4));
+/// let producer = {
+///     let queue = Arc::clone(&queue);
+///     thread::spawn(move || {
+///         for i in 0..10 {
+///             queue.push(i).unwrap();
+///         }
+///         queue.close();
+///     })
+/// };
+///
+/// let mut received = Vec::new();
+/// while let Some(value) = queue.pop() {
+///     received.push(value);
+/// }
+/// producer.join().unwrap();
+/// assert_eq!(received, (0..10).collect::<Vec<_>>());
+/// ```
+pub struct BlockingQueue<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+}
+
+impl<T> BlockingQueue<T> {
+    /// Creates a new, empty queue that holds at most `capacity` values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    pub fn new(capacity: usize) -> Self {
+        BlockingQueue {
+            state: Mutex::new(State {
+                values: ArrayQueue::bounded(capacity, OverflowPolicy::Reject),
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+        }
+    }
+
+    // A panic while the lock is held can't leave the state half updated, so a poisoned lock
+    // is still safe to use.
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Appends `value` to the end of the queue, waiting for room if it is full.
+    ///
+    /// # Returns
+    ///
+    /// `Err(PushError::Closed(value))` if the queue is closed, before or while waiting.
+    pub fn push(&self, value: T) -> Result<(), PushError<T>> {
+        let state = self.lock();
+        let mut state = self
+            .not_full
+            .wait_while(state, |state| !state.closed && state.values.is_full())
+            .unwrap_or_else(PoisonError::into_inner);
+        self.push_locked(&mut state, value)
+    }
+
+    /// Appends `value` to the end of the queue without waiting.
+    ///
+    /// # Returns
+    ///
+    /// `Err(PushError::Full(value))` if the queue is full, or `Err(PushError::Closed(value))` if
+    /// it is closed.
+    pub fn try_push(&self, value: T) -> Result<(), PushError<T>> {
+        let mut state = self.lock();
+        self.push_locked(&mut state, value)
+    }
+
+    fn push_locked(&self, state: &mut State<T>, value: T) -> Result<(), PushError<T>> {
+        if state.closed {
+            return Err(PushError::Closed(value));
+        }
+        state.values.try_append(value).map_err(PushError::Full)?;
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// Removes and returns the first value in the queue, waiting for one if it is empty.
+    ///
+    /// # Returns
+    ///
+    /// `None` once the queue is closed and every value left in it has been popped.
+    pub fn pop(&self) -> Option<T> {
+        let state = self.lock();
+        let mut state = self
+            .not_empty
+            .wait_while(state, |state| !state.closed && state.values.is_empty())
+            .unwrap_or_else(PoisonError::into_inner);
+        self.pop_locked(&mut state)
+    }
+
+    /// Removes and returns the first value in the queue without waiting, or `None` if it is
+    /// empty.
+    pub fn try_pop(&self) -> Option<T> {
+        let mut state = self.lock();
+        self.pop_locked(&mut state)
+    }
+
+    /// Removes and returns the first value in the queue, waiting at most `timeout` for one.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no value arrived in time, or if the queue is closed and empty.
+    pub fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        let state = self.lock();
+        let (mut state, _) = self
+            .not_empty
+            .wait_timeout_while(state, timeout, |state| {
+                !state.closed && state.values.is_empty()
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+        self.pop_locked(&mut state)
+    }
+
+    fn pop_locked(&self, state: &mut State<T>) -> Option<T> {
+        let value = state.values.pop()?;
+        self.not_full.notify_one();
+        Some(value)
+    }
+
+    /// Closes the queue and wakes every thread waiting on it. Values already in the queue can
+    /// still be popped, but nothing else can be pushed.
+    pub fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    /// Returns `true` if `close` has been called.
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    /// Returns the number of values in the queue.
+    pub fn len(&self) -> usize {
+        self.lock().values.len()
+    }
+
+    /// Checks whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().values.is_empty()
+    }
+
+    /// Returns the maximum number of values the queue can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl<T> Queue<T> for BlockingQueue<T> {
+    /// Appends `value` without waiting. The value is discarded if the queue is full or closed;
+    /// use `try_push` to get it back instead.
+    fn append(&mut self, value: T) {
+        let _ = self.try_push(value);
+    }
+
+    /// Removes and returns the first value without waiting, like `try_pop`.
+    fn pop(&mut self) -> Option<T> {
+        self.try_pop()
+    }
+
+    fn size(&self) -> u32 {
+        self.len() as u32
+    }
+}
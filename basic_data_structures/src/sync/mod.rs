@@ -0,0 +1,5 @@
+pub mod blocking_queue;
+pub use blocking_queue::{BlockingQueue, PushError};
+
+pub mod mpsc;
+pub use mpsc::mpsc_queue;
@@ -0,0 +1,157 @@
+use std::{
+    cell::UnsafeCell,
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+struct Node<T> {
+    next: AtomicPtr<Node<T>>,
+    value: Option<T>,
+}
+
+impl<T> Node<T> {
+    fn new_leaked(value: Option<T>) -> *mut Node<T> {
+        Box::into_raw(Box::new(Node { next: AtomicPtr::new(ptr::null_mut()), value }))
+    }
+}
+
+/// The nodes form a singly linked list from `tail` (oldest) to `head` (newest). The node
+/// `tail` points at is always a stub whose value has already been taken.
+struct Inner<T> {
+    head: AtomicPtr<Node<T>>,
+    tail: UnsafeCell<*mut Node<T>>,
+}
+
+// Values are moved between threads, so they must be `Send`. `tail` is only touched by the
+// single `Consumer`.
+unsafe impl<T: Send> Send for Inner<T> {}
+unsafe impl<T: Send> Sync for Inner<T> {}
+
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        let mut current = *self.tail.get_mut();
+        while !current.is_null() {
+            // SAFETY: nothing else can reach the nodes once the last handle is gone, and every
+            // node was allocated with `Box`.
+            let node = unsafe { Box::from_raw(current) };
+            current = node.next.load(Ordering::Relaxed);
+        }
+    }
+}
+
+/// The sending half of a lock-free multi-producer, single-consumer queue.
+///
+/// It can be cloned and handed to as many threads as needed. Pushing never blocks and never
+/// takes a lock: it is a single atomic swap plus a store.
+///
+/// Created by `mpsc_queue`.
+pub struct Producer<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Producer<T> {
+    /// Appends `value` to the end of the queue.
+    pub fn push(&self, value: T) {
+        let node = Node::new_leaked(Some(value));
+        let previous = self.inner.head.swap(node, Ordering::AcqRel);
+        // SAFETY: `previous` stays alive until the consumer moves past it, which can't happen
+        // before its `next` link is set right here.
+        unsafe { (*previous).next.store(node, Ordering::Release) };
+    }
+}
+
+impl<T> Clone for Producer<T> {
+    fn clone(&self) -> Self {
+        Producer { inner: Arc::clone(&self.inner) }
+    }
+}
+
+/// The receiving half of a lock-free multi-producer, single-consumer queue.
+///
+/// There is only ever one consumer, which is what lets `pop` run without any atomic
+/// read-modify-write operation.
+///
+/// Created by `mpsc_queue`.
+pub struct Consumer<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Consumer<T> {
+    /// Removes and returns the oldest value in the queue, or `None` if it is empty.
+    ///
+    /// A producer that is halfway through `push` has already claimed its place in the queue
+    /// but not linked it yet. When that happens `pop` yields the thread until the link shows
+    /// up, so values always come out in the order they were claimed.
+    pub fn pop(&mut self) -> Option<T> {
+        loop {
+            // SAFETY: `&mut self` makes this the only thread touching `tail`, and the node it
+            // points at is never freed by anyone else.
+            unsafe {
+                let tail = *self.inner.tail.get();
+                let next = (*tail).next.load(Ordering::Acquire);
+                if !next.is_null() {
+                    *self.inner.tail.get() = next;
+                    let value = (*next).value.take();
+                    drop(Box::from_raw(tail));
+                    return value;
+                }
+                if self.inner.head.load(Ordering::Acquire) == tail {
+                    return None;
+                }
+            }
+            thread::yield_now();
+        }
+    }
+
+    /// Checks whether the queue is empty, ignoring values whose `push` hasn't finished yet.
+    pub fn is_empty(&self) -> bool {
+        // SAFETY: see `pop`.
+        unsafe {
+            let tail = *self.inner.tail.get();
+            (*tail).next.load(Ordering::Acquire).is_null()
+        }
+    }
+}
+
+impl<T> Iterator for Consumer<T> {
+    type Item = T;
+
+    /// Pops the next value, ending the iteration as soon as the queue is empty.
+    fn next(&mut self) -> Option<T> {
+        self.pop()
+    }
+}
+
+/// Creates a lock-free multi-producer, single-consumer queue, following Dmitry Vyukov's
+/// design, and returns its two halves.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+/// use basic_data_structures::sync::mpsc_queue;
+///
+/// let (producer, mut consumer) = mpsc_queue();
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let producer = producer.clone();
+///         thread::spawn(move || producer.push(i))
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// let mut values: Vec<_> = consumer.by_ref().collect();
+/// values.sort();
+/// assert_eq!(values, vec![0, 1, 2, 3]);
+/// ```
+pub fn mpsc_queue<T>() -> (Producer<T>, Consumer<T>) {
+    let stub = Node::new_leaked(None);
+    let inner = Arc::new(Inner { head: AtomicPtr::new(stub), tail: UnsafeCell::new(stub) });
+    (Producer { inner: Arc::clone(&inner) }, Consumer { inner })
+}
@@ -1,19 +1,324 @@
-use crate::{hash_table::HashTable, linked_list::LinkedList, queue::Queue, stack::Stack};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    hash::{BuildHasher, Hasher},
+    ops::Bound,
+    panic,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    avl_tree::AvlTree,
+    disjoint_set::{kruskal, DisjointSet},
+    doubly_linked_list::DoublyLinkedList,
+    graph::{Graph, GraphKind, NegativeCycle},
+    hash_set::HashSet,
+    hash_table::{
+        hasher::{Fnv1aHasher, SipHasher13},
+        ByteSum, Fnv1a, HashTable, OpenHashTable, SipHash, StringFolding,
+    },
+    linked_list::LinkedList,
+    lru_cache::LruCache,
+    persistent_list::PersistentList,
+    priority_queue::{HeapKind, PriorityQueue},
+    probabilistic::{hyper_log_log::InvalidSketch, BloomFilter, CountMinSketch, HyperLogLog},
+    queue::{ArrayQueue, OverflowPolicy, Queue},
+    skip_list::SkipList,
+    stack::{ArrayStack, Stack},
+    sync::{mpsc_queue, BlockingQueue, PushError},
+    trie::Trie,
+};
 
 #[test]
 fn hash_table() {
     let mut table = HashTable::new();
 
-    table.insert("hola mundooo".to_string());
-    table.insert("nouuuuuuuuuu".to_string());
-    table.insert("bueno ojala funque".to_string());
-    table.insert("siuu".to_string());
+    assert_eq!(table.insert("hola mundooo".to_string(), 1), None);
+    assert_eq!(table.insert("nouuuuuuuuuu".to_string(), 2), None);
+    assert_eq!(table.insert("bueno ojala funque".to_string(), 3), None);
+    assert_eq!(table.insert("siuu".to_string(), 4), None);
+    assert_eq!(table.len(), 4);
+
+    assert_eq!(table.insert("siuu".to_string(), 5), Some(4));
+    assert_eq!(table.len(), 4);
+    assert_eq!(table.get("siuu"), Some(&5));
+    assert!(table.contains_key("hola mundooo"));
+    assert!(!table.contains_key("adios"));
+
+    *table.get_mut("nouuuuuuuuuu").unwrap() += 10;
+    assert_eq!(table.get("nouuuuuuuuuu"), Some(&12));
+
+    assert_eq!(table.remove("bueno ojala funque"), Some(3));
+    assert_eq!(table.remove("bueno ojala funque"), None);
+    assert_eq!(table.len(), 3);
+
+    table.clear();
+    assert!(table.is_empty());
+    assert_eq!(table.get("siuu"), None);
+}
+
+#[test]
+fn hash_table_collisions() {
+    let mut table = HashTable::new_with_custom_size(3);
+
+    for i in 0..100 {
+        table.insert(i, i * 2);
+    }
+    assert_eq!(table.len(), 100);
+    for i in 0..100 {
+        assert_eq!(table.get(&i), Some(&(i * 2)));
+    }
+    for i in (0..100).step_by(2) {
+        assert_eq!(table.remove(&i), Some(i * 2));
+    }
+    assert_eq!(table.len(), 50);
+    assert!(!table.contains_key(&10));
+    assert!(table.contains_key(&11));
+}
+
+#[test]
+fn hash_table_resize() {
+    let mut table = HashTable::new();
+    assert_eq!(table.rows_count(), 10);
+
+    for i in 0..200 {
+        table.insert(i.to_string(), i);
+        assert!(table.load_factor() <= table.max_load_factor());
+    }
+    assert!(table.rows_count() > 200);
+    for i in 0..200 {
+        assert_eq!(table.get(&i.to_string()), Some(&i));
+    }
+
+    for i in 0..198 {
+        assert_eq!(table.remove(&i.to_string()), Some(i));
+    }
+    assert_eq!(table.rows_count(), 10);
+    for i in 198..200 {
+        assert_eq!(table.get(&i.to_string()), Some(&i));
+    }
+
+    let table: HashTable<u32, u32> = HashTable::with_capacity(30);
+    assert_eq!(table.rows_count(), 40);
+    let table: HashTable<u32, u32> = HashTable::with_load_factor(2.0);
+    assert_eq!(table.max_load_factor(), 2.0);
+}
+
+#[test]
+fn hashers() {
+    let mut fnv = Fnv1aHasher::default();
+    assert_eq!(fnv.finish(), 0xcbf29ce484222325);
+    fnv.write(b"a");
+    assert_eq!(fnv.finish(), 0xaf63dc4c8601ec8c);
+
+    // The SipHash-1-3 reference vectors: the key is the bytes 0..16 and the message of
+    // length `n` is the bytes 0..n.
+    let vectors: [u64; 16] = [
+        0xabac0158050fc4dc,
+        0xc9f49bf37d57ca93,
+        0x82cb9b024dc7d44d,
+        0x8bf80ab8e7ddf7fb,
+        0xcf75576088d38328,
+        0xdef9d52f49533b67,
+        0xc50d2b50c59f22a7,
+        0xd3927d989bb11140,
+        0x369095118d299a8e,
+        0x25a48eb36c063de4,
+        0x79de85ee92ff097f,
+        0x70c118c1f94dc352,
+        0x78a384b157b4d9a2,
+        0x306f760c1229ffa7,
+        0x605aa111c0f95d34,
+        0xd320d86d2a519956,
+    ];
+    let (k0, k1) = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+    let message: Vec<u8> = (0..16).collect();
+    for (len, &expected) in vectors.iter().enumerate() {
+        let mut sip_hasher = SipHasher13::new_with_keys(k0, k1);
+        sip_hasher.write(&message[..len]);
+        assert_eq!(sip_hasher.finish(), expected, "message of length {}", len);
+
+        // Splitting the message between writes gives the same hash.
+        let mut split = SipHasher13::new_with_keys(k0, k1);
+        split.write(&message[..len / 3]);
+        split.write(&message[len / 3..len]);
+        assert_eq!(split.finish(), expected);
+    }
+    assert_eq!(SipHash::with_keys(k0, k1).build_hasher().finish(), vectors[0]);
+    assert_eq!(SipHash::new().hash_one(42u64), SipHash::with_keys(0, 0).hash_one(42u64));
+    assert_ne!(SipHash::with_keys(1, 2).hash_one(42u64), SipHash::new().hash_one(42u64));
+}
+
+fn fill_and_check<S: BuildHasher>(mut table: HashTable<String, usize, S>) {
+    for i in 0..300 {
+        table.insert(i.to_string(), i);
+    }
+    for i in 0..300 {
+        assert_eq!(table.get(&i.to_string()), Some(&i));
+    }
+    for i in 0..300 {
+        assert_eq!(table.remove(&i.to_string()), Some(i));
+    }
+    assert!(table.is_empty());
+}
+
+#[test]
+fn hash_table_with_hashers() {
+    fill_and_check(HashTable::with_hasher(ByteSum::default()));
+    fill_and_check(HashTable::with_hasher(StringFolding::default()));
+    fill_and_check(HashTable::with_hasher(Fnv1a::default()));
+    fill_and_check(HashTable::with_capacity_and_hasher(300, SipHash::with_keys(7, 11)));
+}
+
+#[test]
+fn open_hash_table() {
+    let mut table = OpenHashTable::new();
+
+    assert_eq!(table.insert("hola mundooo".to_string(), 1), None);
+    assert_eq!(table.insert("siuu".to_string(), 4), None);
+    assert_eq!(table.insert("siuu".to_string(), 5), Some(4));
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.get("siuu"), Some(&5));
+    *table.get_mut("hola mundooo").unwrap() += 10;
+    assert_eq!(table.get("hola mundooo"), Some(&11));
+    assert!(!table.contains_key("adios"));
+    assert_eq!(table.remove("siuu"), Some(5));
+    assert_eq!(table.remove("siuu"), None);
+
+    for i in 0..200 {
+        table.insert(i.to_string(), i);
+        assert!(table.load_factor() <= table.max_load_factor());
+    }
+    assert!(table.rows_count() > 200);
+    for i in 0..200 {
+        assert_eq!(table.remove(&i.to_string()), Some(i));
+    }
+    assert_eq!(table.rows_count(), 10);
+    assert_eq!(table.len(), 1);
+
+    table.clear();
+    assert!(table.is_empty());
+}
+
+/// Advances the linear congruential generator behind the randomized tests and returns its new
+/// state. Use the high bits, the low ones have short periods.
+fn next_random(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    *seed
+}
+
+#[test]
+fn open_hash_table_matches_chaining() {
+    // `ByteSum` makes lots of keys collide, which exercises long probe sequences, Robin Hood
+    // swaps and backward shifts.
+    let mut open = OpenHashTable::with_hasher(ByteSum::default());
+    let mut chaining = HashTable::with_hasher(ByteSum::default());
+    let mut reference = HashMap::new();
+
+    let mut seed: u64 = 42;
+    for _ in 0..5_000 {
+        let random = next_random(&mut seed);
+        let key = ((random >> 33) % 500).to_string();
+        let value = random >> 50;
+        if (random >> 20) % 3 == 0 {
+            let expected = reference.remove(&key);
+            assert_eq!(open.remove(&key), expected);
+            assert_eq!(chaining.remove(&key), expected);
+        } else {
+            let expected = reference.insert(key.clone(), value);
+            assert_eq!(open.insert(key.clone(), value), expected);
+            assert_eq!(chaining.insert(key, value), expected);
+        }
+        assert_eq!(open.len(), reference.len());
+        assert_eq!(chaining.len(), reference.len());
+    }
+    for i in 0..500 {
+        let key = i.to_string();
+        assert_eq!(open.get(&key), reference.get(&key));
+        assert_eq!(open.contains_key(&key), reference.contains_key(&key));
+    }
+}
+
+#[test]
+fn hash_table_iterators() {
+    let table: HashTable<u32, u32> = (0..50).map(|i| (i, i * i)).collect();
+    assert_eq!(table.iter().len(), 50);
+    let mut keys: Vec<u32> = table.keys().copied().collect();
+    keys.sort();
+    assert_eq!(keys, (0..50).collect::<Vec<_>>());
+    assert_eq!(table.values().sum::<u32>(), (0..50).map(|i| i * i).sum::<u32>());
+    assert!((&table).into_iter().all(|(key, value)| key * key == *value));
+
+    let open: OpenHashTable<u32, u32> = table.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(open.iter().len(), 50);
+    assert!(open.iter().all(|(key, value)| table.get(key) == Some(value)));
+    assert_eq!(open.keys().len(), 50);
+    assert_eq!(open.values().len(), table.values().len());
+    assert_eq!(open.values().sum::<u32>(), table.values().sum::<u32>());
+    let mut keys = open.keys();
+    keys.next();
+    assert!(keys.clone().eq(keys));
+}
+
+fn sorted<'a>(values: impl Iterator<Item = &'a u32>) -> Vec<u32> {
+    let mut values: Vec<u32> = values.copied().collect();
+    values.sort();
+    values
+}
+
+#[test]
+fn hash_set() {
+    let mut set = HashSet::new();
+    assert!(set.insert("hola mundooo".to_string()));
+    assert!(set.insert("siuu".to_string()));
+    assert!(!set.insert("siuu".to_string()));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains("siuu"));
+    assert!(set.remove("siuu"));
+    assert!(!set.remove("siuu"));
+    assert!(!set.contains("siuu"));
+    set.clear();
+    assert!(set.is_empty());
+
+    let a: HashSet<u32> = (0..10).collect();
+    let b: HashSet<u32> = (5..15).collect();
+    let evens: HashSet<u32> = (0..10).step_by(2).collect();
+    let far: HashSet<u32> = (100..110).collect();
+
+    assert_eq!(sorted(a.union(&b)), (0..15).collect::<Vec<_>>());
+    assert_eq!(sorted(a.intersection(&b)), (5..10).collect::<Vec<_>>());
+    assert_eq!(sorted(b.intersection(&a)), (5..10).collect::<Vec<_>>());
+    assert_eq!(sorted(a.difference(&b)), (0..5).collect::<Vec<_>>());
+    assert_eq!(sorted(b.difference(&a)), (10..15).collect::<Vec<_>>());
+    assert_eq!(
+        sorted(a.symmetric_difference(&b)),
+        (0..5).chain(10..15).collect::<Vec<_>>()
+    );
+
+    assert!(evens.is_subset(&a));
+    assert!(a.is_superset(&evens));
+    assert!(!a.is_subset(&b));
+    assert!(a.is_disjoint(&far));
+    assert!(!a.is_disjoint(&b));
+    assert_eq!(a.iter().len(), 10);
+
+    let union: HashSet<u32> = a.union(&b).copied().collect();
+    let rebuilt: HashSet<u32> = (0..15).rev().collect();
+    assert_eq!(union, rebuilt);
+    assert_ne!(union, a);
 }
 
 #[test]
 fn queue() {
-    let mut list: Box<dyn Queue<u32>> = Box::new(LinkedList::new());
+    check_queue(Box::new(LinkedList::new()));
+    check_queue(Box::new(DoublyLinkedList::new()));
+    check_queue(Box::new(ArrayQueue::new()));
+    check_queue(Box::new(ArrayQueue::with_capacity(1)));
+    check_queue(Box::new(BlockingQueue::new(8)));
+}
 
+fn check_queue(mut list: Box<dyn Queue<u32>>) {
     list.append(4);
     list.append(9);
     list.append(7);
@@ -37,13 +342,21 @@ fn queue() {
 
 #[test]
 fn stack() {
-    let mut stack: Box<dyn Stack<u32>> = Box::new(LinkedList::new());
+    check_stack(Box::new(LinkedList::new()));
+    check_stack(Box::new(DoublyLinkedList::new()));
+    check_stack(Box::new(ArrayStack::new()));
+}
 
+fn check_stack(mut stack: Box<dyn Stack<u32>>) {
     stack.prepend(9);
     stack.prepend(8);
     stack.prepend(7);
 
     assert_eq!(stack.size(), 3);
+    assert_eq!(stack.peek(), Some(&7));
+    *stack.peek_mut().unwrap() += 10;
+    assert_eq!(stack.pop(), Some(17));
+    stack.prepend(7);
     assert_eq!(stack.pop(), Some(7));
     assert_eq!(stack.pop(), Some(8));
     assert_eq!(stack.size(), 1);
@@ -51,7 +364,295 @@ fn stack() {
     stack.pop();
 
     assert_eq!(stack.size(), 0);
-    assert_eq!(stack.pop(), None)
+    assert_eq!(stack.pop(), None);
+    assert_eq!(stack.peek(), None);
+    assert!(stack.is_empty());
+
+    stack.prepend(1);
+    stack.prepend(2);
+    assert!(!stack.is_empty());
+    stack.clear();
+    assert!(stack.is_empty());
+    assert_eq!(stack.pop(), None);
+}
+
+#[test]
+fn array_stack() {
+    let mut stack: ArrayStack<u32> = (1..=3).collect();
+    assert_eq!(stack.len(), 3);
+    assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+    stack.extend(vec![4, 5]);
+    assert_eq!(stack.peek(), Some(&5));
+
+    stack.reserve(100);
+    assert!(stack.capacity() >= 105);
+    stack.clear();
+    stack.shrink_to_fit();
+    assert_eq!(stack.capacity(), 0);
+    assert_eq!(ArrayStack::<u32>::with_capacity(16).capacity(), 16);
+}
+
+#[test]
+fn array_queue() {
+    let mut queue = ArrayQueue::with_capacity(4);
+    for i in 0..3 {
+        queue.append(i);
+    }
+    assert_eq!(queue.pop(), Some(0));
+    assert_eq!(queue.pop(), Some(1));
+    // The next appends wrap around the end of the buffer before it has to grow.
+    for i in 3..10 {
+        queue.append(i);
+    }
+    assert_eq!(queue.len(), 8);
+    assert_eq!(queue.capacity(), 8);
+    assert_eq!(queue.iter().copied().collect::<Vec<_>>(), (2..10).collect::<Vec<_>>());
+    *queue.peek_mut().unwrap() = 20;
+    assert_eq!(queue.peek(), Some(&20));
+
+    queue.clear();
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop(), None);
+
+    let mut rejecting = ArrayQueue::bounded(2, OverflowPolicy::Reject);
+    rejecting.append(1);
+    assert_eq!(rejecting.try_append(2), Ok(()));
+    assert!(rejecting.is_full());
+    assert_eq!(rejecting.try_append(3), Err(3));
+    rejecting.append(3);
+    assert_eq!(rejecting.capacity(), 2);
+    assert_eq!(rejecting.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+    let mut overwriting: ArrayQueue<u32> = ArrayQueue::bounded(3, OverflowPolicy::Overwrite);
+    overwriting.extend(1..=5);
+    assert_eq!(overwriting.len(), 3);
+    assert_eq!(overwriting.pop(), Some(3));
+    overwriting.append(6);
+    assert_eq!(overwriting.iter().copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+}
+
+#[test]
+fn priority_queue() {
+    let values = vec![7, 3, 9, 1, 4, 4, 8, 0, 6];
+    let mut sorted = values.clone();
+    sorted.sort();
+
+    let mut min = PriorityQueue::new_min();
+    for value in values.iter() {
+        min.push(*value);
+    }
+    assert_eq!(min.peek(), Some(&0));
+    let mut popped = Vec::new();
+    while let Some(value) = min.pop() {
+        popped.push(value);
+    }
+    assert_eq!(popped, sorted);
+    assert!(min.is_empty());
+
+    let max = PriorityQueue::from_vec(values.clone(), HeapKind::Max);
+    assert_eq!(max.peek(), Some(&9));
+    assert_eq!(max.len(), values.len());
+    let mut descending = sorted.clone();
+    descending.reverse();
+    assert_eq!(max.into_sorted_vec(), descending);
+
+    let min = PriorityQueue::from_iter_with_kind(values.iter().copied(), HeapKind::Min);
+    assert_eq!(min.into_sorted_vec(), sorted);
+
+    let mut collected: PriorityQueue<u32> = (0..100).map(|i| (i * 37) % 101).collect();
+    assert_eq!(collected.kind(), HeapKind::Max);
+    collected.extend(vec![500, 200]);
+    assert_eq!(collected.pop(), Some(500));
+    assert_eq!(collected.pop(), Some(200));
+
+    let mut queue: Box<dyn Queue<u32>> = Box::new(PriorityQueue::new_min());
+    queue.append(5);
+    queue.append(2);
+    queue.append(8);
+    assert_eq!(queue.size(), 3);
+    assert_eq!(queue.pop(), Some(2));
+    assert_eq!(queue.pop(), Some(5));
+}
+
+#[test]
+fn blocking_queue() {
+    let queue = BlockingQueue::new(2);
+    assert_eq!(queue.capacity(), 2);
+    assert_eq!(queue.try_pop(), None);
+    assert_eq!(queue.pop_timeout(Duration::from_millis(10)), None);
+
+    queue.push(1).unwrap();
+    queue.try_push(2).unwrap();
+    assert_eq!(queue.try_push(3), Err(PushError::Full(3)));
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.pop(), Some(1));
+    assert_eq!(queue.pop_timeout(Duration::from_millis(10)), Some(2));
+    assert!(queue.is_empty());
+
+    queue.push(4).unwrap();
+    queue.close();
+    assert!(queue.is_closed());
+    assert_eq!(queue.push(5), Err(PushError::Closed(5)));
+    assert_eq!(queue.pop(), Some(4));
+    assert_eq!(queue.pop(), None);
+}
+
+#[test]
+fn blocking_queue_threads() {
+    let queue = Arc::new(BlockingQueue::new(4));
+    let producers: Vec<_> = (0..4)
+        .map(|producer| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                for i in 0..250 {
+                    queue.push(producer * 1000 + i).unwrap();
+                }
+            })
+        })
+        .collect();
+    let consumer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || {
+            let mut received = Vec::new();
+            while let Some(value) = queue.pop() {
+                received.push(value);
+            }
+            received
+        })
+    };
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    queue.close();
+    let mut received = consumer.join().unwrap();
+    received.sort();
+    let mut expected: Vec<u32> = (0..4).flat_map(|p| (0..250).map(move |i| p * 1000 + i)).collect();
+    expected.sort();
+    assert_eq!(received, expected);
+}
+
+#[test]
+fn blocking_queue_close_wakes_waiters() {
+    let queue: Arc<BlockingQueue<u32>> = Arc::new(BlockingQueue::new(1));
+    let waiter = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || queue.pop())
+    };
+    thread::sleep(Duration::from_millis(20));
+    queue.close();
+    assert_eq!(waiter.join().unwrap(), None);
+
+    let queue = Arc::new(BlockingQueue::new(1));
+    queue.push(1).unwrap();
+    let blocked_producer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || queue.push(2))
+    };
+    thread::sleep(Duration::from_millis(20));
+    queue.close();
+    assert_eq!(blocked_producer.join().unwrap(), Err(PushError::Closed(2)));
+}
+
+#[test]
+fn mpsc_queue_single_thread() {
+    let (producer, mut consumer) = mpsc_queue();
+    assert!(consumer.is_empty());
+    assert_eq!(consumer.pop(), None);
+
+    producer.push("a".to_string());
+    producer.clone().push("b".to_string());
+    assert!(!consumer.is_empty());
+    assert_eq!(consumer.pop(), Some("a".to_string()));
+    assert_eq!(consumer.pop(), Some("b".to_string()));
+    assert_eq!(consumer.pop(), None);
+
+    // Values left in the queue are dropped with it.
+    producer.push("c".to_string());
+    drop(consumer);
+    producer.push("d".to_string());
+}
+
+#[test]
+fn mpsc_queue_stress() {
+    const PRODUCERS: usize = 8;
+    const PER_PRODUCER: usize = 20_000;
+
+    let (producer, mut consumer) = mpsc_queue();
+    let handles: Vec<_> = (0..PRODUCERS)
+        .map(|id| {
+            let producer = producer.clone();
+            thread::spawn(move || {
+                for i in 0..PER_PRODUCER {
+                    producer.push((id, i));
+                }
+            })
+        })
+        .collect();
+    drop(producer);
+
+    let mut next_expected = [0; PRODUCERS];
+    let mut received = 0;
+    while received < PRODUCERS * PER_PRODUCER {
+        match consumer.pop() {
+            Some((id, i)) => {
+                // Every producer's values come out in the order it pushed them, so checking
+                // for the next expected index rules out both losses and duplicates.
+                assert_eq!(i, next_expected[id]);
+                next_expected[id] += 1;
+                received += 1;
+            }
+            None => thread::yield_now(),
+        }
+    }
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(consumer.pop(), None);
+    assert!(next_expected.iter().all(|&count| count == PER_PRODUCER));
+}
+
+#[test]
+fn persistent_list() {
+    let empty = PersistentList::new();
+    let one = empty.cons(1);
+    let two = one.cons(2);
+    let other_two = one.cons(20);
+
+    assert!(empty.is_empty());
+    assert_eq!(one.iter().copied().collect::<Vec<_>>(), vec![1]);
+    assert_eq!(two.iter().copied().collect::<Vec<_>>(), vec![2, 1]);
+    assert_eq!(other_two.iter().copied().collect::<Vec<_>>(), vec![20, 1]);
+    assert!(two.tail().ptr_eq(&one));
+    assert!(other_two.tail().ptr_eq(&one));
+    assert!(!two.ptr_eq(&other_two));
+
+    let (head, tail) = two.uncons().unwrap();
+    assert_eq!(*head, 2);
+    assert_eq!(tail, one);
+    assert_eq!(empty.uncons(), None);
+    assert!(empty.tail().is_empty());
+    assert_eq!(two.len(), 2);
+
+    let collected: PersistentList<u32> = vec![2, 1].into_iter().collect();
+    assert_eq!(collected, two);
+    assert!(!collected.ptr_eq(&two));
+    assert_eq!(two.to_string(), " -> 2 -> 1");
+
+    drop(one);
+    assert_eq!(two.tail().head(), Some(&1));
+
+    // Snapshots share everything but their newest node, and a long history drops fine.
+    let mut history = vec![PersistentList::new()];
+    for step in 0..100_000u32 {
+        let next = history.last().unwrap().cons(step);
+        history.push(next);
+    }
+    assert_eq!(history[10].len(), 10);
+    assert_eq!(history[10].head(), Some(&9));
+    assert_eq!(history.last().unwrap().head(), Some(&99_999));
+    drop(history);
 }
 
 #[test]
@@ -74,3 +675,1216 @@ fn list() {
     assert_eq!(list.find(|x| { x == "" }), Some("".to_string()));
     assert_eq!(list.size(), 2);
 }
+
+#[test]
+fn list_iterators() {
+    let mut list: LinkedList<u32> = (1..=5).collect();
+    assert_eq!(list.size(), 5);
+
+    let iter = list.iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+    for value in list.iter_mut() {
+        *value *= 10;
+    }
+    for value in &mut list {
+        *value += 1;
+    }
+    assert_eq!((&list).into_iter().sum::<u32>(), 155);
+
+    list.extend(vec![6, 7]);
+    assert_eq!(list.size(), 7);
+    assert_eq!(list.iter().last(), Some(&7));
+
+    let mut visited = Vec::new();
+    list.for_each(|value, i| visited.push((i, *value)));
+    assert_eq!(visited.len(), 7);
+    assert_eq!(visited[6], (6, 7));
+
+    list.for_each_mut(|value, i| *value = i as u32);
+    let owned: Vec<u32> = list.into_iter().collect();
+    assert_eq!(owned, vec![0, 1, 2, 3, 4, 5, 6]);
+
+    let mut empty: LinkedList<u32> = LinkedList::new();
+    assert_eq!(empty.iter().next(), None);
+    empty.extend(Some(3));
+    assert_eq!(empty.peek_head(), Some(3));
+}
+
+#[test]
+fn list_cursors() {
+    let mut list: LinkedList<u32> = (0..6).collect();
+
+    let mut cursor = list.cursor_front();
+    assert_eq!(cursor.index(), Some(0));
+    assert_eq!(cursor.peek_next(), Some(&1));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&2));
+
+    let mut cursor = list.cursor_front_mut();
+    while let Some(&mut value) = cursor.current() {
+        if value % 2 == 0 {
+            cursor.remove_current();
+        } else {
+            *cursor.current().unwrap() = value * 10;
+            cursor.insert_after(value * 10 + 1);
+            cursor.move_next();
+            cursor.move_next();
+        }
+    }
+    assert_eq!(cursor.index(), None);
+    cursor.insert_after(99);
+    assert_eq!(cursor.current(), None);
+    assert_eq!(list.size(), 7);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 11, 30, 31, 50, 51, 99]);
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.peek_next(), Some(&mut 31));
+    let tail = cursor.split_after();
+    assert_eq!(list.size(), 3);
+    assert_eq!(tail.size(), 4);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 11, 30]);
+    assert_eq!(tail.iter().copied().collect::<Vec<_>>(), vec![31, 50, 51, 99]);
+
+    let mut empty: LinkedList<u32> = LinkedList::new();
+    let mut cursor = empty.cursor_front_mut();
+    assert_eq!(cursor.remove_current(), None);
+    assert_eq!(cursor.split_after().size(), 0);
+    cursor.insert_after(1);
+    assert_eq!(empty.peek_head(), Some(1));
+}
+
+#[test]
+fn list_sort() {
+    let mut seed: u64 = 21;
+    let mut values = Vec::new();
+    for _ in 0..150_000 {
+        values.push((next_random(&mut seed) >> 40) as u32);
+    }
+    let mut list: LinkedList<u32> = values.iter().copied().collect();
+    assert!(!list.is_sorted());
+
+    // Sorting relinks the nodes, so every value stays where it was in memory.
+    let mut addresses: Vec<*const u32> = list.iter().map(|value| value as *const u32).collect();
+    list.sort();
+    values.sort();
+    assert!(list.is_sorted());
+    assert_eq!(list.size(), 150_000);
+    assert!(list.iter().eq(values.iter()));
+    let mut after: Vec<*const u32> = list.iter().map(|value| value as *const u32).collect();
+    addresses.sort();
+    after.sort();
+    assert_eq!(addresses, after);
+
+    // The sort is stable: values with the same key keep their order.
+    let mut pairs: LinkedList<(u32, usize)> =
+        values.iter().rev().enumerate().map(|(index, &value)| (value % 100, index)).collect();
+    pairs.sort_by_key(|&(key, _)| key);
+    let mut expected: Vec<(u32, usize)> =
+        values.iter().rev().enumerate().map(|(index, &value)| (value % 100, index)).collect();
+    expected.sort_by_key(|&(key, _)| key);
+    assert!(pairs.iter().eq(expected.iter()));
+
+    list.sort_by(|a, b| b.cmp(a));
+    assert!(list.iter().eq(values.iter().rev()));
+
+    let mut empty: LinkedList<u32> = LinkedList::new();
+    empty.sort();
+    assert!(empty.is_sorted());
+    let mut one: LinkedList<u32> = [7].into_iter().collect();
+    one.sort();
+    assert!(one.iter().eq(&[7]));
+}
+
+#[test]
+fn list_merge() {
+    let mut evens: LinkedList<u32> = (0..120_000).map(|value| value * 2).collect();
+    let odds: LinkedList<u32> = (0..60_000).map(|value| value * 2 + 1).collect();
+    evens.merge(odds);
+    assert_eq!(evens.size(), 180_000);
+    assert!(evens.is_sorted());
+    let copy = evens.clone();
+    assert_eq!(copy.size(), 180_000);
+    assert!(copy.iter().eq(evens.iter()));
+    assert!(evens.iter().take(120_000).copied().eq(0..120_000));
+    assert!(evens.iter().skip(120_000).copied().eq((60_000..120_000).map(|value| value * 2)));
+
+    // Values of the list come before equal values of the one merged into it.
+    let mut left: LinkedList<(u32, char)> = [(1, 'a'), (2, 'a'), (2, 'b')].into_iter().collect();
+    let right: LinkedList<(u32, char)> = [(0, 'z'), (2, 'a'), (3, 'c')].into_iter().collect();
+    left.merge(right);
+    let merged: Vec<(u32, char)> = left.iter().copied().collect();
+    assert_eq!(merged, vec![(0, 'z'), (1, 'a'), (2, 'a'), (2, 'a'), (2, 'b'), (3, 'c')]);
+
+    let mut empty = LinkedList::new();
+    empty.merge([1, 2].into_iter().collect());
+    assert!(empty.iter().eq(&[1, 2]));
+    empty.merge(LinkedList::new());
+    assert_eq!(empty.size(), 2);
+}
+
+#[test]
+fn doubly_linked_list() {
+    let mut list = DoublyLinkedList::new();
+    assert!(list.is_empty());
+    assert_eq!(list.pop_back(), None);
+
+    list.push_back(2);
+    list.push_back(3);
+    list.push_front(1);
+    assert_eq!(list.size(), 3);
+    assert_eq!(list.front(), Some(&1));
+    assert_eq!(list.back(), Some(&3));
+
+    *list.back_mut().unwrap() = 30;
+    *list.front_mut().unwrap() = 10;
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 2, 30]);
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![30, 2, 10]);
+
+    for value in &mut list {
+        *value += 1;
+    }
+    let mut iter = list.iter();
+    assert_eq!(iter.next(), Some(&11));
+    assert_eq!(iter.next_back(), Some(&31));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    assert_eq!(list.pop_back(), Some(31));
+    assert_eq!(list.pop_front(), Some(11));
+    assert_eq!(list.pop_back(), Some(3));
+    assert!(list.is_empty());
+    assert_eq!(list.front(), None);
+
+    let list: DoublyLinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+    let copy = list.clone();
+    assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec!["c", "b", "a"]);
+    assert_eq!(copy.to_string(), " <-> a <-> b <-> c");
+}
+
+#[test]
+fn avl_tree() {
+    let mut tree = AvlTree::new();
+    assert_eq!(tree.height(), 0);
+    assert_eq!(tree.min(), None);
+    assert_eq!(tree.floor(&5), None);
+
+    // Sorted insertions are the worst case for an unbalanced tree.
+    for i in 0..1_000 {
+        assert_eq!(tree.insert(i, i * 10), None);
+    }
+    assert_eq!(tree.len(), 1_000);
+    assert_eq!(tree.height(), 10);
+    assert_eq!(tree.insert(500, 0), Some(5_000));
+    assert_eq!(tree.get(&500), Some(&0));
+    *tree.get_mut(&500).unwrap() = 5_000;
+    assert_eq!(tree.min(), Some((&0, &0)));
+    assert_eq!(tree.max(), Some((&999, &9_990)));
+    assert!(tree.keys().copied().eq(0..1_000));
+    assert_eq!(tree.values().len(), 1_000);
+
+    for i in (0..1_000).filter(|i| i % 10 != 0) {
+        assert_eq!(tree.remove(&i), Some(i * 10));
+    }
+    assert_eq!(tree.remove(&1), None);
+    assert_eq!(tree.len(), 100);
+    assert!(tree.height() <= 8);
+
+    assert_eq!(tree.floor(&25), Some((&20, &200)));
+    assert_eq!(tree.floor(&20), Some((&20, &200)));
+    assert_eq!(tree.floor(&-1), None);
+    assert_eq!(tree.ceiling(&25), Some((&30, &300)));
+    assert_eq!(tree.ceiling(&990), Some((&990, &9_900)));
+    assert_eq!(tree.ceiling(&991), None);
+
+    let keys = |range: Vec<(&i32, &i32)>| range.into_iter().map(|(key, _)| *key).collect::<Vec<_>>();
+    assert_eq!(keys(tree.range(15..45).collect()), vec![20, 30, 40]);
+    assert_eq!(keys(tree.range(20..=40).collect()), vec![20, 30, 40]);
+    assert_eq!(keys(tree.range(20..40).collect()), vec![20, 30]);
+    assert_eq!(keys(tree.range((Bound::Excluded(20), Bound::Included(40))).collect()), vec![30, 40]);
+    assert_eq!(keys(tree.range(..15).collect()), vec![0, 10]);
+    assert_eq!(keys(tree.range(975..).collect()), vec![980, 990]);
+    assert_eq!(tree.range(..).count(), 100);
+    assert_eq!(tree.range(21..29).count(), 0);
+    assert_eq!(tree.range(2_000..).count(), 0);
+
+    tree.clear();
+    assert!(tree.is_empty());
+    assert_eq!(tree.height(), 0);
+
+    let mut names = AvlTree::new();
+    names.insert("b".to_string(), 2);
+    names.insert("a".to_string(), 1);
+    assert_eq!(names.get("a"), Some(&1));
+    assert!(names.contains_key("b"));
+    assert_eq!(names.remove("b"), Some(2));
+    assert_eq!(format!("{:?}", names), r#"{"a": 1}"#);
+}
+
+#[test]
+fn avl_tree_matches_btree_map() {
+    let mut tree = AvlTree::new();
+    let mut reference = BTreeMap::new();
+
+    let mut seed: u64 = 7;
+    for _ in 0..20_000 {
+        let random = next_random(&mut seed);
+        let key = (random >> 33) % 2_000;
+        if (random >> 20) % 3 == 0 {
+            assert_eq!(tree.remove(&key), reference.remove(&key));
+        } else {
+            assert_eq!(tree.insert(key, random), reference.insert(key, random));
+        }
+        assert_eq!(tree.len(), reference.len());
+    }
+
+    // The AVL bound on the height: 1.44 * log2(n + 2).
+    let bound = 1.44 * ((tree.len() + 2) as f64).log2();
+    assert!((tree.height() as f64) <= bound, "height {} above {}", tree.height(), bound);
+    assert!(tree.iter().eq(reference.iter()));
+    assert!(tree.range(500..1_500).eq(reference.range(500..1_500)));
+    for key in [0, 777, 1_999, 5_000] {
+        assert_eq!(tree.floor(&key), reference.range(..=key).next_back());
+        assert_eq!(tree.ceiling(&key), reference.range(key..).next());
+    }
+}
+
+#[test]
+fn skip_list() {
+    let mut list = SkipList::with_seed(1);
+    assert_eq!(list.levels(), 0);
+    assert_eq!(list.first(), None);
+    assert_eq!(list.last(), None);
+
+    for i in (0..100).rev() {
+        assert_eq!(list.insert(i * 2, i), None);
+    }
+    assert_eq!(list.len(), 100);
+    assert_eq!(list.insert(10, 0), Some(5));
+    assert_eq!(list.get(&10), Some(&0));
+    *list.get_mut(&10).unwrap() = 5;
+    assert_eq!(list.get(&11), None);
+    assert!(list.contains_key(&198));
+    assert_eq!(list.first(), Some((&0, &0)));
+    assert_eq!(list.last(), Some((&198, &99)));
+    assert!(list.keys().copied().eq((0..100).map(|i| i * 2)));
+    assert!(list.values().copied().eq(0..100));
+
+    let keys = |list: &SkipList<u32, u32>, range: (Bound<u32>, Bound<u32>)| {
+        list.range(range).map(|(key, _)| *key).collect::<Vec<_>>()
+    };
+    assert_eq!(keys(&list, (Bound::Included(3), Bound::Excluded(10))), vec![4, 6, 8]);
+    assert_eq!(keys(&list, (Bound::Included(4), Bound::Included(10))), vec![4, 6, 8, 10]);
+    assert_eq!(keys(&list, (Bound::Excluded(4), Bound::Unbounded)).len(), 97);
+    assert_eq!(keys(&list, (Bound::Unbounded, Bound::Excluded(4))), vec![0, 2]);
+    assert_eq!(keys(&list, (Bound::Included(5), Bound::Excluded(6))), Vec::<u32>::new());
+    assert_eq!(keys(&list, (Bound::Included(500), Bound::Unbounded)), Vec::<u32>::new());
+
+    for i in 0..100 {
+        assert_eq!(list.remove(&(i * 2)), Some(i));
+        assert_eq!(list.remove(&(i * 2)), None);
+    }
+    assert!(list.is_empty());
+    assert_eq!(list.levels(), 0);
+
+    // Removed slots are reused.
+    list.extend((0..50).map(|i| (i, i)));
+    assert!(list.keys().copied().eq(0..50));
+    list.clear();
+    assert!(list.iter().next().is_none());
+
+    // The same seed gives the same shape.
+    let mut a = SkipList::with_seed(99);
+    let mut b = SkipList::with_seed(99);
+    for i in 0..1_000 {
+        a.insert(i, ());
+        b.insert(i, ());
+        assert_eq!(a.levels(), b.levels());
+    }
+
+    let mut names = SkipList::with_seed(0);
+    names.insert("b".to_string(), 2);
+    names.insert("a".to_string(), 1);
+    assert_eq!(names.get("a"), Some(&1));
+    assert_eq!(names.remove("b"), Some(2));
+    assert_eq!(format!("{:?}", names), r#"{"a": 1}"#);
+}
+
+#[test]
+fn skip_list_matches_btree_map() {
+    let mut list = SkipList::with_seed(2024);
+    let mut reference = BTreeMap::new();
+
+    let mut seed: u64 = 11;
+    for _ in 0..20_000 {
+        let random = next_random(&mut seed);
+        let key = (random >> 33) % 2_000;
+        if (random >> 20) % 3 == 0 {
+            assert_eq!(list.remove(&key), reference.remove(&key));
+        } else {
+            assert_eq!(list.insert(key, random), reference.insert(key, random));
+        }
+        assert_eq!(list.len(), reference.len());
+    }
+
+    assert!(list.iter().eq(reference.iter()));
+    assert!(list.range(500..1_500).eq(reference.range(500..1_500)));
+    assert!(list.range(..=700).eq(reference.range(..=700)));
+    assert_eq!(list.first(), reference.iter().next());
+    assert_eq!(list.last(), reference.iter().next_back());
+    // About log2(n) levels are expected; anything close to the maximum would mean the levels
+    // are not random.
+    assert!(list.levels() <= 24, "{} levels", list.levels());
+}
+
+#[test]
+fn trie() {
+    let mut trie = Trie::new();
+    assert_eq!(trie.longest_common_prefix(), "");
+    assert_eq!(trie.starts_with("").count(), 0);
+
+    for (i, word) in ["árbol", "arco", "arcoíris", "ardilla", "barco", "ñandú"].iter().enumerate() {
+        assert_eq!(trie.insert(word, i), None);
+    }
+    assert_eq!(trie.insert("arco", 10), Some(1));
+    assert_eq!(trie.len(), 6);
+    assert!(trie.contains("arcoíris"));
+    assert!(!trie.contains("arc"));
+    assert!(!trie.contains("arcos"));
+    assert_eq!(trie.get("ñandú"), Some(&5));
+    *trie.get_mut("barco").unwrap() += 1;
+    assert_eq!(trie.get("barco"), Some(&5));
+
+    let keys: Vec<String> = trie.starts_with("arc").collect();
+    assert_eq!(keys, vec!["arco", "arcoíris"]);
+    let keys: Vec<String> = trie.starts_with("ar").collect();
+    assert_eq!(keys, vec!["arco", "arcoíris", "ardilla"]);
+    assert_eq!(trie.starts_with("á").collect::<Vec<_>>(), vec!["árbol"]);
+    assert_eq!(trie.starts_with("z").count(), 0);
+    let entries: Vec<(String, &usize)> = trie.with_prefix("arco").collect();
+    assert_eq!(entries, vec![("arco".to_string(), &10), ("arcoíris".to_string(), &2)]);
+
+    // Keys come out in the same order as sorted `String`s.
+    let sorted: BTreeSet<String> = trie.keys().collect();
+    assert!(trie.keys().eq(sorted.into_iter()));
+    assert_eq!(trie.longest_common_prefix(), "");
+    let ar: Trie<()> = trie.starts_with("ar").map(|key| (key, ())).collect();
+    assert_eq!(ar.longest_common_prefix(), "ar");
+
+    assert_eq!(trie.remove("arc"), None);
+    assert_eq!(trie.remove("arco"), Some(10));
+    assert_eq!(trie.remove("arco"), None);
+    assert!(trie.contains("arcoíris"));
+    assert_eq!(trie.remove("arcoíris"), Some(2));
+    // The branch of the removed words is pruned, so nothing starts with "arc" any more.
+    assert_eq!(trie.starts_with("arc").count(), 0);
+    assert_eq!(trie.len(), 4);
+
+    let mut single = Trie::new();
+    single.insert("canción", ());
+    assert_eq!(single.longest_common_prefix(), "canción");
+    single.insert("", ());
+    assert_eq!(single.longest_common_prefix(), "");
+    assert_eq!(single.keys().collect::<Vec<_>>(), vec!["", "canción"]);
+
+    trie.clear();
+    assert!(trie.is_empty());
+    assert_eq!(trie.iter().count(), 0);
+}
+
+/// Builds the graph
+///
+/// ```text
+/// 0 - 1 - 3 - 5
+///  \     /
+///   2 - 4       6
+/// ```
+///
+/// with the edges pointing rightwards if it is directed.
+fn sample_graph(kind: GraphKind) -> Graph<char, usize> {
+    let mut graph = Graph::new(kind);
+    for name in "abcdefg".chars() {
+        graph.add_node(name);
+    }
+    for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 4), (4, 3), (3, 5)] {
+        graph.add_edge(from, to, from + to);
+    }
+    graph
+}
+
+#[test]
+fn graph() {
+    let mut graph = sample_graph(GraphKind::Directed);
+    assert!(graph.is_directed());
+    assert_eq!(graph.node_count(), 7);
+    assert_eq!(graph.edge_count(), 6);
+    assert_eq!(graph[3], 'd');
+    graph[6] = 'z';
+    assert_eq!(graph.node(6), Some(&'z'));
+    assert_eq!(graph.node(7), None);
+    assert_eq!(graph.neighbors(0).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(graph.neighbors(3).collect::<Vec<_>>(), vec![5]);
+    assert_eq!(graph.edges_from(4).collect::<Vec<_>>(), vec![(3, &7)]);
+    assert_eq!(graph.edge(4).map(|edge| (edge.from, edge.to)), Some((4, 3)));
+
+    let undirected = sample_graph(GraphKind::Undirected);
+    assert_eq!(undirected.neighbors(3).collect::<Vec<_>>(), vec![1, 4, 5]);
+    assert_eq!(undirected.neighbors(6).count(), 0);
+
+    let mut looped = Graph::new_undirected();
+    let node = looped.add_node(());
+    looped.add_edge(node, node, ());
+    assert_eq!(looped.neighbors(node).collect::<Vec<_>>(), vec![node]);
+}
+
+#[test]
+fn graph_traversals() {
+    let directed = sample_graph(GraphKind::Directed);
+    let undirected = sample_graph(GraphKind::Undirected);
+
+    // The same traversal comes out of every queue and every stack.
+    let bfs_orders = [
+        directed.bfs(0).collect::<Vec<_>>(),
+        directed.bfs_with(0, LinkedList::new()).collect(),
+        directed.bfs_with(0, DoublyLinkedList::new()).collect(),
+        directed.bfs_with(0, BlockingQueue::new(7)).collect(),
+    ];
+    for order in &bfs_orders {
+        assert_eq!(order, &vec![0, 1, 2, 3, 4, 5]);
+    }
+    let dfs_orders = [
+        directed.dfs(0).collect::<Vec<_>>(),
+        directed.dfs_with(0, LinkedList::new()).collect(),
+        directed.dfs_with(0, DoublyLinkedList::new()).collect(),
+    ];
+    for order in &dfs_orders {
+        assert_eq!(order, &vec![0, 1, 3, 5, 2, 4]);
+    }
+
+    assert_eq!(directed.bfs(4).collect::<Vec<_>>(), vec![4, 3, 5]);
+    assert_eq!(undirected.bfs(5).collect::<Vec<_>>(), vec![5, 3, 1, 4, 0, 2]);
+    assert_eq!(undirected.dfs(5).collect::<Vec<_>>(), vec![5, 3, 1, 0, 2, 4]);
+    assert_eq!(undirected.bfs(6).collect::<Vec<_>>(), vec![6]);
+
+    let tree = directed.bfs(0).into_tree();
+    assert_eq!(tree.root(), 0);
+    assert_eq!(tree.parent(0), None);
+    assert_eq!(tree.parent(3), Some(1));
+    assert_eq!(tree.path_to(5), Some(vec![0, 1, 3, 5]));
+    assert_eq!(tree.path_to(0), Some(vec![0]));
+    assert!(!tree.contains(6));
+    assert_eq!(tree.path_to(6), None);
+    assert_eq!(tree.parents(), &[None, Some(0), Some(0), Some(1), Some(2), Some(3), None]);
+
+    // Depth-first goes all the way around the cycle, so 2 is reached last, through 4.
+    let tree = undirected.dfs(0).into_tree();
+    assert_eq!(tree.path_to(4), Some(vec![0, 1, 3, 4]));
+    assert_eq!(tree.path_to(2), Some(vec![0, 1, 3, 4, 2]));
+
+    // Stopping early still gives the parents found so far.
+    let mut bfs = undirected.bfs(0);
+    assert_eq!(bfs.next(), Some(0));
+    assert_eq!(bfs.parents()[1..3], [Some(0), Some(0)]);
+    assert_eq!(bfs.parents()[3], None);
+}
+
+#[test]
+fn shortest_paths() {
+    let directed = sample_graph(GraphKind::Directed);
+    for paths in [directed.dijkstra(0), directed.bellman_ford(0).unwrap()] {
+        assert_eq!(paths.source(), 0);
+        // 0 -> 1 -> 3 costs 1 + 4 and 0 -> 2 -> 4 -> 3 costs 2 + 6 + 7.
+        assert_eq!(paths.distance(3), Some(5));
+        assert_eq!(paths.path_to(5), Some(vec![0, 1, 3, 5]));
+        assert_eq!(paths.distance(5), Some(13));
+        assert_eq!(paths.distance(6), None);
+        assert_eq!(paths.path_to(6), None);
+        assert_eq!(paths.distances()[..3], [Some(0), Some(1), Some(2)]);
+        assert_eq!(paths.tree().parent(4), Some(2));
+    }
+    assert_eq!(directed.astar(0, 5, |_| 0), Some((13, vec![0, 1, 3, 5])));
+    assert_eq!(directed.astar(3, 0, |_| 0), None);
+    assert_eq!(directed.astar(4, 4, |_| 0), Some((0, vec![4])));
+
+    // Float weights.
+    let mut graph = Graph::new_undirected();
+    for _ in 0..3 {
+        graph.add_node(());
+    }
+    graph.add_edge(0, 1, 0.5);
+    graph.add_edge(1, 2, 0.25);
+    graph.add_edge(0, 2, 1.0);
+    assert_eq!(graph.dijkstra(0).distance(2), Some(0.75));
+    assert_eq!(graph.astar(2, 0, |_| 0.0), Some((0.75, vec![2, 1, 0])));
+
+    // A negative edge is fine for Bellman-Ford in a directed graph...
+    let mut graph = Graph::new_directed();
+    for _ in 0..4 {
+        graph.add_node(());
+    }
+    graph.add_edge(0, 1, 2);
+    graph.add_edge(1, 2, -5);
+    graph.add_edge(0, 2, 1);
+    let paths = graph.bellman_ford(0).unwrap();
+    assert_eq!(paths.distance(2), Some(-3));
+    assert_eq!(paths.path_to(2), Some(vec![0, 1, 2]));
+
+    // ...until it closes a negative cycle.
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(3, 1, 2);
+    let error = graph.bellman_ford(0).unwrap_err();
+    let mut cycle = error.cycle().to_vec();
+    let start = cycle.iter().position(|&node| node == 1).unwrap();
+    cycle.rotate_left(start);
+    assert_eq!(cycle, vec![1, 2, 3]);
+    assert!(error.to_string().starts_with("negative cycle through nodes"));
+    // A cycle that can't be reached from the source doesn't matter.
+    let mut unreachable = Graph::new_directed();
+    for _ in 0..3 {
+        unreachable.add_node(());
+    }
+    unreachable.add_edge(1, 2, -1);
+    unreachable.add_edge(2, 1, -1);
+    assert_eq!(unreachable.bellman_ford(0).unwrap().distance(1), None);
+
+    // In an undirected graph, a negative edge is a cycle on its own.
+    let mut graph = Graph::new_undirected();
+    graph.add_node(());
+    graph.add_node(());
+    graph.add_edge(0, 1, -1);
+    assert!(matches!(graph.bellman_ford(0), Err(NegativeCycle { .. })));
+}
+
+#[test]
+fn astar_with_an_inconsistent_heuristic() {
+    // The heuristic never overestimates, but drops by 3 along the edge 1 -> 3 of weight 1, so
+    // node 3 is first reached through 2 and must be explored again once 1 is.
+    let mut graph = Graph::new_directed();
+    for _ in 0..5 {
+        graph.add_node(());
+    }
+    graph.add_edge(0, 1, 1);
+    graph.add_edge(1, 3, 1);
+    graph.add_edge(0, 2, 1);
+    graph.add_edge(2, 3, 2);
+    graph.add_edge(3, 4, 3);
+    let heuristic = |node| if node == 1 { 3 } else { 0 };
+    assert_eq!(graph.astar(0, 4, heuristic), Some((5, vec![0, 1, 3, 4])));
+    assert_eq!(graph.dijkstra(0).distance(4), Some(5));
+}
+
+#[test]
+fn shortest_paths_reject_bad_weights() {
+    let mut graph = Graph::new_directed();
+    for _ in 0..3 {
+        graph.add_node(());
+    }
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(0, 2, f64::NAN);
+    assert!(panic::catch_unwind(|| graph.dijkstra(0)).is_err());
+    assert!(panic::catch_unwind(|| graph.astar(0, 1, |_| 0.0)).is_err());
+    assert!(panic::catch_unwind(|| graph.bellman_ford(0)).is_err());
+
+    let mut graph = Graph::new_directed();
+    for _ in 0..3 {
+        graph.add_node(());
+    }
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, -1.0);
+    assert!(panic::catch_unwind(|| graph.dijkstra(0)).is_err());
+    assert!(panic::catch_unwind(|| graph.astar(0, 2, |_| f64::NAN)).is_err());
+    assert_eq!(graph.bellman_ford(0).unwrap().distance(2), Some(0.0));
+}
+
+#[test]
+fn shortest_paths_on_a_grid() {
+    // A maze-like grid where moving into a cell costs its weight, and walls can't be entered.
+    const SIZE: usize = 30;
+    let mut seed: u64 = 3;
+    let mut weights = Vec::new();
+    for _ in 0..SIZE * SIZE {
+        weights.push(match (next_random(&mut seed) >> 33) % 10 {
+            0 | 1 => None,
+            cost => Some(cost),
+        });
+    }
+    weights[0] = Some(1);
+    weights[SIZE * SIZE - 1] = Some(1);
+
+    let mut grid = Graph::new_directed();
+    for cell in 0..SIZE * SIZE {
+        grid.add_node((cell % SIZE, cell / SIZE));
+    }
+    for cell in 0..SIZE * SIZE {
+        let (x, y) = grid[cell];
+        let mut neighbors = vec![];
+        if x > 0 {
+            neighbors.push(cell - 1);
+        }
+        if x + 1 < SIZE {
+            neighbors.push(cell + 1);
+        }
+        if y > 0 {
+            neighbors.push(cell - SIZE);
+        }
+        if y + 1 < SIZE {
+            neighbors.push(cell + SIZE);
+        }
+        for neighbor in neighbors {
+            if let Some(cost) = weights[neighbor] {
+                grid.add_edge(cell, neighbor, cost);
+            }
+        }
+    }
+
+    let goal = SIZE * SIZE - 1;
+    let dijkstra = grid.dijkstra(0);
+    assert_eq!(grid.bellman_ford(0).unwrap().distances(), dijkstra.distances());
+
+    // Every move costs at least 2, so twice the Manhattan distance never overestimates.
+    let (gx, gy) = grid[goal];
+    let manhattan = |node: usize| {
+        let (x, y) = grid[node];
+        2 * (x.abs_diff(gx) + y.abs_diff(gy)) as u64
+    };
+    let (length, path) = grid.astar(0, goal, manhattan).expect("the seed gives a solvable maze");
+    assert_eq!(Some(length), dijkstra.distance(goal));
+    assert_eq!(path.first(), Some(&0));
+    assert_eq!(path.last(), Some(&goal));
+    let cost: u64 = path[1..].iter().map(|&cell| weights[cell].unwrap()).sum();
+    assert_eq!(cost, length);
+}
+
+#[test]
+fn disjoint_set() {
+    let mut sets = DisjointSet::new(8);
+    assert_eq!(sets.len(), 8);
+    assert_eq!(sets.set_count(), 8);
+    assert!((0..8).all(|element| sets.find(element) == element));
+
+    assert!(sets.union(0, 1));
+    assert!(sets.union(2, 3));
+    assert!(sets.union(1, 3));
+    assert!(!sets.union(0, 2));
+    assert!(sets.union(5, 6));
+    assert_eq!(sets.set_count(), 4);
+    assert!(sets.connected(0, 3));
+    assert!(!sets.connected(3, 4));
+    assert_eq!(sets.find(0), sets.find(2));
+    assert_eq!(sets.set_size(2), 4);
+    assert_eq!(sets.set_size(6), 2);
+    assert_eq!(sets.set_size(7), 1);
+    assert_eq!(sets.sets(), vec![vec![0, 1, 2, 3], vec![4], vec![5, 6], vec![7]]);
+
+    let element = sets.make_set();
+    assert_eq!(element, 8);
+    assert_eq!(sets.set_count(), 5);
+    assert!(sets.union(8, 7));
+    assert_eq!(sets.set_size(8), 2);
+
+    // A long chain: union by rank keeps the trees flat.
+    let mut chain = DisjointSet::new(100_000);
+    for element in 1..chain.len() {
+        chain.union(element - 1, element);
+    }
+    assert_eq!(chain.set_count(), 1);
+    assert_eq!(chain.set_size(12_345), 100_000);
+
+    assert!(DisjointSet::new(0).is_empty());
+}
+
+#[test]
+fn connected_components() {
+    // Labelling components with union-find finds the same ones as breadth-first searches.
+    let mut graph = Graph::new_undirected();
+    for _ in 0..200 {
+        graph.add_node(());
+    }
+    let mut seed: u64 = 5;
+    for _ in 0..150 {
+        let random = next_random(&mut seed);
+        graph.add_edge((random >> 33) as usize % 200, (random >> 12) as usize % 200, ());
+    }
+
+    let mut sets = DisjointSet::new(graph.node_count());
+    for edge in graph.edges() {
+        sets.union(edge.from, edge.to);
+    }
+    let mut components = Vec::new();
+    let mut labelled = vec![false; graph.node_count()];
+    for node in 0..graph.node_count() {
+        if !labelled[node] {
+            let mut component: Vec<usize> = graph.bfs(node).collect();
+            component.iter().for_each(|&node| labelled[node] = true);
+            component.sort();
+            components.push(component);
+        }
+    }
+    assert_eq!(sets.set_count(), components.len());
+    assert_eq!(sets.sets(), components);
+}
+
+#[test]
+fn kruskal_minimum_spanning_tree() {
+    // The classic example: the MST weighs 37.
+    let edges = [
+        (0, 1, 4),
+        (0, 7, 8),
+        (1, 2, 8),
+        (1, 7, 11),
+        (2, 3, 7),
+        (2, 8, 2),
+        (2, 5, 4),
+        (3, 4, 9),
+        (3, 5, 14),
+        (4, 5, 10),
+        (5, 6, 2),
+        (6, 7, 1),
+        (6, 8, 6),
+        (7, 8, 7),
+    ];
+    let tree = kruskal(9, &edges);
+    assert_eq!(tree.len(), 8);
+    assert_eq!(tree.iter().map(|&edge| edges[edge].2).sum::<i32>(), 37);
+    assert_eq!(tree[..3], [11, 5, 10]);
+
+    // Two components give a forest, and nothing is kept without edges.
+    let forest = kruskal(5, &[(0, 1, 1.5), (3, 4, 0.5), (1, 0, 0.1)]);
+    assert_eq!(forest, vec![2, 1]);
+    assert_eq!(kruskal::<u32>(3, &[]), Vec::<usize>::new());
+    let floats = [(0, 1, 0.5), (1, 2, f64::NAN), (0, 2, 1.5)];
+    let result = panic::catch_unwind(|| kruskal(3, &floats));
+    let message = result.unwrap_err();
+    assert_eq!(message.downcast_ref::<&str>(), Some(&"kruskal can't sort NaN weights"));
+
+    // A maze: a random spanning tree of a grid, which matches Prim's algorithm in weight.
+    const SIZE: usize = 20;
+    let mut seed: u64 = 9;
+    let mut edges = Vec::new();
+    for cell in 0..SIZE * SIZE {
+        if cell % SIZE != SIZE - 1 {
+            edges.push((cell, cell + 1, next_random(&mut seed) >> 40));
+        }
+        if cell + SIZE < SIZE * SIZE {
+            edges.push((cell, cell + SIZE, next_random(&mut seed) >> 40));
+        }
+    }
+    let maze = kruskal(SIZE * SIZE, &edges);
+    assert_eq!(maze.len(), SIZE * SIZE - 1);
+    let mut sets = DisjointSet::new(SIZE * SIZE);
+    for &edge in &maze {
+        assert!(sets.union(edges[edge].0, edges[edge].1), "the tree has a cycle");
+    }
+
+    let mut in_tree = vec![false; SIZE * SIZE];
+    let mut best = vec![u64::MAX; SIZE * SIZE];
+    best[0] = 0;
+    let mut prim_weight = 0;
+    for _ in 0..SIZE * SIZE {
+        let node = (0..SIZE * SIZE)
+            .filter(|&node| !in_tree[node])
+            .min_by_key(|&node| best[node])
+            .unwrap();
+        in_tree[node] = true;
+        prim_weight += best[node];
+        for &(a, b, weight) in &edges {
+            let other = if a == node {
+                b
+            } else if b == node {
+                a
+            } else {
+                continue;
+            };
+            if !in_tree[other] && weight < best[other] {
+                best[other] = weight;
+            }
+        }
+    }
+    assert_eq!(maze.iter().map(|&edge| edges[edge].2).sum::<u64>(), prim_weight);
+}
+
+#[test]
+fn lru_cache() {
+    let mut cache = LruCache::new(3);
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&evicted);
+    cache.set_on_evict(move |key, value| log.lock().unwrap().push((key, value)));
+    assert_eq!(cache.capacity(), 3);
+    assert_eq!(cache.hit_rate(), None);
+
+    for (key, value) in [("a", 1), ("b", 2), ("c", 3)] {
+        assert_eq!(cache.put(key.to_string(), value), None);
+    }
+    assert_eq!(cache.len(), 3);
+    assert_eq!(cache.get("a"), Some(&1));
+    assert_eq!(cache.get("z"), None);
+    // Peeking doesn't save "b" from being the least recently used entry.
+    assert_eq!(cache.peek("b"), Some(&2));
+    assert_eq!(cache.peek_lru(), Some((&"b".to_string(), &2)));
+
+    assert_eq!(cache.put("d".to_string(), 4), None);
+    assert_eq!(*evicted.lock().unwrap(), vec![("b".to_string(), 2)]);
+    assert!(!cache.contains("b"));
+    assert_eq!(cache.len(), 3);
+
+    // Updating an entry doesn't evict anything and makes it the most recently used one.
+    assert_eq!(cache.put("c".to_string(), 30), Some(3));
+    *cache.get_mut("a").unwrap() += 10;
+    let order: Vec<_> = cache.iter().map(|(key, value)| (key.as_str(), *value)).collect();
+    assert_eq!(order, vec![("a", 11), ("c", 30), ("d", 4)]);
+    assert_eq!(evicted.lock().unwrap().len(), 1);
+
+    assert_eq!(cache.hits(), 2);
+    assert_eq!(cache.misses(), 1);
+    assert_eq!(cache.hit_rate(), Some(2.0 / 3.0));
+    cache.reset_stats();
+    assert_eq!(cache.hits(), 0);
+
+    // Explicit removals don't go through the callback.
+    assert_eq!(cache.pop("c"), Some(30));
+    assert_eq!(cache.pop("c"), None);
+    assert_eq!(cache.pop_lru(), Some(("d".to_string(), 4)));
+    assert_eq!(cache.len(), 1);
+    cache.put("e".to_string(), 5);
+    cache.put("f".to_string(), 6);
+    assert_eq!(format!("{:?}", cache), r#"{"f": 6, "e": 5, "a": 11}"#);
+    assert_eq!(evicted.lock().unwrap().len(), 1);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.pop_lru(), None);
+
+    // The cache, callback included, can move to another thread.
+    let cache = thread::spawn(move || {
+        cache.put("g".to_string(), 7);
+        cache
+    })
+    .join()
+    .unwrap();
+    assert_eq!(cache.iter().count(), 1);
+}
+
+#[test]
+fn lru_cache_matches_model() {
+    // The model keeps the keys from the least to the most recently used one.
+    let mut model: Vec<(u64, u64)> = Vec::new();
+    let mut cache = LruCache::with_hasher(16, Fnv1a::default());
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&evicted);
+    cache.set_on_evict(move |key, _| log.lock().unwrap().push(key));
+
+    let mut seed: u64 = 21;
+    for _ in 0..10_000 {
+        let random = next_random(&mut seed);
+        let key = (random >> 33) % 40;
+        let position = model.iter().position(|&(other, _)| other == key);
+        if (random >> 20) % 2 == 0 {
+            let expected = position.map(|position| model.remove(position));
+            assert_eq!(cache.get(&key).copied(), expected.map(|(_, value)| value));
+            if let Some(entry) = expected {
+                model.push(entry);
+            }
+        } else {
+            let expected = position.map(|position| model.remove(position).1);
+            assert_eq!(cache.put(key, random), expected);
+            if expected.is_none() && model.len() == 16 {
+                let (lru, _) = model.remove(0);
+                assert_eq!(evicted.lock().unwrap().pop(), Some(lru));
+            }
+            model.push((key, random));
+        }
+        assert!(evicted.lock().unwrap().is_empty());
+        assert_eq!(cache.len(), model.len());
+    }
+    let order: Vec<_> = cache.iter().map(|(&key, &value)| (key, value)).collect();
+    model.reverse();
+    assert_eq!(order, model);
+}
+
+#[test]
+fn bloom_filter() {
+    for target in [0.1, 0.01, 0.001] {
+        let mut filter = BloomFilter::with_seed(10_000, target, 17);
+        assert!(filter.is_empty());
+        for i in 0..10_000u32 {
+            filter.insert(&i);
+        }
+        // No false negatives.
+        assert!((0..10_000u32).all(|i| filter.contains(&i)));
+
+        let false_positives = (10_000..110_000u32).filter(|i| filter.contains(i)).count();
+        let measured = false_positives as f64 / 100_000.0;
+        assert!(measured < target * 1.5, "measured {} for a target of {}", measured, target);
+        assert!(measured > target / 2.0, "measured {} for a target of {}", measured, target);
+        // The filter's own estimate agrees with what was measured.
+        let estimate = filter.false_positive_rate();
+        assert!((estimate - measured).abs() < target / 2.0, "{} vs {}", estimate, measured);
+        // Only inserts mistaken for values already in the filter are not counted.
+        assert!(filter.len() <= 10_000 && filter.len() as f64 >= 10_000.0 * (1.0 - target));
+    }
+
+    // 1% with 1 000 items: about 9.6 bits per item and 7 hash functions.
+    let mut words = BloomFilter::with_seed(1_000, 0.01, 0);
+    assert_eq!(words.bit_count(), 9_586);
+    assert_eq!(words.hash_count(), 7);
+    assert!(words.insert("hola"));
+    assert!(!words.insert("hola"));
+    assert!(words.contains("hola"));
+    assert!(!words.contains("chau"));
+    assert_eq!(words.len(), 1);
+
+    assert_eq!(
+        format!("{:?}", words),
+        "BloomFilter { bit_count: 9586, hash_count: 7, seed: 0, len: 1 }"
+    );
+    let copy = words.clone();
+    assert!(copy.contains("hola"));
+
+    // The seed picks the hash functions: filters with the same seed agree on every value.
+    let mut small = BloomFilter::with_size(256, 3, 0);
+    let mut same = BloomFilter::with_size(256, 3, 0);
+    let mut other = BloomFilter::with_size(256, 3, 1);
+    for i in 0..50u32 {
+        small.insert(&i);
+        same.insert(&i);
+        other.insert(&i);
+    }
+    assert!((0..10_000u32).all(|i| same.contains(&i) == small.contains(&i)));
+    assert!((0..10_000u32).any(|i| other.contains(&i) != small.contains(&i)));
+
+    words.clear();
+    assert!(!words.contains("hola"));
+    assert_eq!(words.false_positive_rate(), 0.0);
+}
+
+#[test]
+fn count_min_sketch() {
+    let (epsilon, delta) = (0.001, 0.01);
+    let mut sketch = CountMinSketch::with_seed(epsilon, delta, 3);
+    assert_eq!(sketch.width(), 2_719);
+    assert_eq!(sketch.depth(), 5);
+
+    // A skewed stream: value `v` shows up about 20 000 / (v + 1) times.
+    let mut counts = HashMap::new();
+    let mut seed: u64 = 13;
+    for _ in 0..200_000 {
+        let value = 20_000 / ((next_random(&mut seed) >> 33) % 20_000 + 1);
+        sketch.increment(&value);
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    assert_eq!(sketch.total(), 200_000);
+
+    let bound = (epsilon * sketch.total() as f64) as u64;
+    let mut over_bound = 0;
+    for (value, &count) in &counts {
+        let estimate = sketch.estimate(value);
+        assert!(estimate >= count);
+        if estimate - count > bound {
+            over_bound += 1;
+        }
+    }
+    assert!(over_bound as f64 <= delta * counts.len() as f64 + 1.0, "{} estimates off", over_bound);
+    assert_eq!(sketch.estimate(&u64::MAX), 0);
+
+    let mut words = CountMinSketch::with_size(64, 4, 0);
+    words.add("a", 5);
+    words.increment("b");
+    let mut more = CountMinSketch::with_size(64, 4, 0);
+    more.add("a", 2);
+    words.merge(&more);
+    assert_eq!(words.estimate("a"), 7);
+    assert_eq!(words.estimate("b"), 1);
+    assert_eq!(words.total(), 8);
+    let copy = words.clone();
+    assert_eq!(copy.estimate("a"), 7);
+    assert_eq!(format!("{:?}", copy), "CountMinSketch { width: 64, depth: 4, seed: 0, total: 8 }");
+    words.clear();
+    assert_eq!(words.estimate("a"), 0);
+    assert_eq!(copy.total(), 8);
+}
+
+#[test]
+fn hyper_log_log() {
+    for precision in [8, 10, 14] {
+        let mut sketch = HyperLogLog::with_seed(precision, 5);
+        assert!(sketch.is_empty());
+        assert_eq!(sketch.count(), 0);
+        assert_eq!(sketch.registers().len(), 1 << precision);
+
+        // Every count stays within four standard errors, small ones included.
+        let bound = 4.0 * sketch.relative_error();
+        let mut added = 0u64;
+        for target in [10u64, 1_000, 100_000] {
+            while added < target {
+                sketch.add(&added);
+                added += 1;
+            }
+            let error = (sketch.count() as f64 - target as f64).abs() / target as f64;
+            assert!(error < bound, "{} off by {} with precision {}", target, error, precision);
+        }
+        // Adding the same values again changes nothing.
+        let before = sketch.clone();
+        assert!(!(0..1_000u64).any(|value| sketch.add(&value)));
+        assert_eq!(sketch, before);
+    }
+
+    // Merging gives the count of the union.
+    let mut evens = HyperLogLog::with_seed(12, 0);
+    let mut odds = HyperLogLog::with_seed(12, 0);
+    for value in 0..50_000u32 {
+        if value % 2 == 0 {
+            evens.add(&value);
+        } else {
+            odds.add(&value);
+        }
+    }
+    let mut both = HyperLogLog::with_seed(12, 0);
+    (0..50_000u32).for_each(|value| {
+        both.add(&value);
+    });
+    evens.merge(&odds);
+    assert_eq!(evens, both);
+    let error = (evens.count() as f64 - 50_000.0).abs() / 50_000.0;
+    assert!(error < 4.0 * evens.relative_error());
+
+    // The registers survive a round trip through bytes.
+    let bytes = both.to_bytes();
+    assert_eq!(bytes.len(), 9 + 4096);
+    let back = HyperLogLog::<u32>::from_bytes(&bytes).unwrap();
+    assert_eq!(back, both);
+    assert_eq!(back.count(), both.count());
+    assert_eq!(HyperLogLog::<u32>::from_bytes(&bytes[..5]), Err(InvalidSketch::Truncated));
+    assert_eq!(
+        HyperLogLog::<u32>::from_bytes(&bytes[..100]),
+        Err(InvalidSketch::RegisterCount { expected: 4096, found: 91 })
+    );
+    let mut wrong = bytes.clone();
+    wrong[0] = 30;
+    assert_eq!(HyperLogLog::<u32>::from_bytes(&wrong), Err(InvalidSketch::Precision(30)));
+    wrong[0] = 12;
+    wrong[9] = 60;
+    assert_eq!(HyperLogLog::<u32>::from_bytes(&wrong), Err(InvalidSketch::Register(60)));
+
+    let mut words = HyperLogLog::with_seed(4, 1);
+    words.add("paso");
+    words.add("paso");
+    assert_eq!(words.count(), 1);
+    let copy = words.clone();
+    words.clear();
+    assert!(words.is_empty());
+    assert_ne!(copy, words);
+    assert_eq!(copy.count(), 1);
+    assert_eq!(format!("{:?}", copy), "HyperLogLog { precision: 4, seed: 1 }");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trips() {
+    let list: LinkedList<u32> = (1..=4).collect();
+    let json = serde_json::to_string(&list).unwrap();
+    assert_eq!(json, "[1,2,3,4]");
+    let back: LinkedList<u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+    let doubly: DoublyLinkedList<u32> = (1..=4).collect();
+    let json = serde_json::to_string(&doubly).unwrap();
+    assert_eq!(json, "[1,2,3,4]");
+    let back: DoublyLinkedList<u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+    let persistent: PersistentList<u32> = (1..=4).collect();
+    let back: PersistentList<u32> =
+        serde_json::from_str(&serde_json::to_string(&persistent).unwrap()).unwrap();
+    assert_eq!(back, persistent);
+
+    let mut queue = ArrayQueue::bounded(3, OverflowPolicy::Overwrite);
+    for i in 1..=5 {
+        queue.append(i);
+    }
+    let json = serde_json::to_string(&queue).unwrap();
+    assert_eq!(json, "[3,4,5]");
+    let mut back: ArrayQueue<u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.pop(), Some(3));
+
+    let mut stack = ArrayStack::new();
+    for i in 1..=3 {
+        stack.prepend(i);
+    }
+    let json = serde_json::to_string(&stack).unwrap();
+    assert_eq!(json, "[1,2,3]");
+    let mut back: ArrayStack<u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.pop(), Some(3));
+    assert_eq!(back.pop(), Some(2));
+
+    let heap = PriorityQueue::from_vec(vec![4, 1, 3, 2], HeapKind::Min);
+    let json = serde_json::to_string(&heap).unwrap();
+    let back: PriorityQueue<u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.kind(), HeapKind::Min);
+    assert_eq!(back.into_sorted_vec(), vec![1, 2, 3, 4]);
+    // The stored values don't have to be in heap order.
+    let back: PriorityQueue<u32> =
+        serde_json::from_str(r#"{"kind":"Max","values":[1,5,2,4]}"#).unwrap();
+    assert_eq!(back.into_sorted_vec(), vec![5, 4, 2, 1]);
+
+    let table: HashTable<String, u32> = (0..50).map(|i| (i.to_string(), i)).collect();
+    let json = serde_json::to_string(&table).unwrap();
+    assert!(json.starts_with('{'));
+    let back: HashTable<String, u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.len(), 50);
+    assert!((0..50).all(|i| back.get(&i.to_string()) == Some(&i)));
+
+    let open: OpenHashTable<String, u32> = (0..50).map(|i| (i.to_string(), i)).collect();
+    let back: OpenHashTable<String, u32> =
+        serde_json::from_str(&serde_json::to_string(&open).unwrap()).unwrap();
+    assert_eq!(back.len(), 50);
+    assert!((0..50).all(|i| back.get(&i.to_string()) == Some(&i)));
+
+    let tree: AvlTree<u32, String> = (0..20).map(|i| (i, i.to_string())).collect();
+    let json = serde_json::to_string(&tree).unwrap();
+    assert!(json.starts_with(r#"{"0":"0","1":"1","2":"2""#));
+    let back: AvlTree<u32, String> = serde_json::from_str(&json).unwrap();
+    assert!(back.iter().eq(tree.iter()));
+
+    let skip_list: SkipList<u32, u32> = (0..20).map(|i| (i, i * i)).collect();
+    let back: SkipList<u32, u32> =
+        serde_json::from_str(&serde_json::to_string(&skip_list).unwrap()).unwrap();
+    assert!(back.iter().eq(skip_list.iter()));
+
+    let trie: Trie<u32> = [("sol", 1), ("sal", 2), ("señal", 3)].into_iter().collect();
+    let json = serde_json::to_string(&trie).unwrap();
+    assert_eq!(json, r#"{"sal":2,"señal":3,"sol":1}"#);
+    let back: Trie<u32> = serde_json::from_str(&json).unwrap();
+    assert!(back.iter().eq(trie.iter()));
+
+    let mut graph = Graph::new_directed();
+    let a = graph.add_node("a".to_string());
+    let b = graph.add_node("b".to_string());
+    graph.add_edge(a, b, 2.5);
+    graph.add_edge(b, b, 1.0);
+    let json = serde_json::to_string(&graph).unwrap();
+    let back: Graph<String, f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.kind(), GraphKind::Directed);
+    assert_eq!(back[b], "b");
+    assert_eq!(back.edges(), graph.edges());
+    let broken = r#"{"kind":"Undirected","nodes":[1],"edges":[{"from":0,"to":3,"weight":1}]}"#;
+    assert!(serde_json::from_str::<Graph<u32, u32>>(broken).is_err());
+
+    let set: HashSet<u32> = (0..20).collect();
+    let back: HashSet<u32> = serde_json::from_str(&serde_json::to_string(&set).unwrap()).unwrap();
+    assert_eq!(back, set);
+
+    let mut sketch = HyperLogLog::with_seed(4, 9);
+    (0..100u32).for_each(|value| {
+        sketch.add(&value);
+    });
+    let json = serde_json::to_string(&sketch).unwrap();
+    let back: HyperLogLog<u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, sketch);
+    let broken = r#"{"precision":4,"seed":9,"registers":[1,2,3]}"#;
+    assert!(serde_json::from_str::<HyperLogLog<u32>>(broken).is_err());
+}
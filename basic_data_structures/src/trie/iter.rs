@@ -0,0 +1,79 @@
+use std::{collections::btree_map, iter::FusedIterator};
+
+use super::Node;
+
+/// An iterator over the keys of a `Trie` and their values, in lexicographic order of the keys.
+///
+/// Keys are built while walking down the trie, so each one is a newly allocated `String`.
+///
+/// Created by `Trie::iter` and `Trie::with_prefix`.
+pub struct Iter<'a, V> {
+    /// The node the walk starts at, until its own value has been looked at.
+    start: Option<&'a Node<V>>,
+    /// The children left to visit on each level below the start.
+    stack: Vec<btree_map::Iter<'a, char, Node<V>>>,
+    /// The key of the node the walk is at.
+    key: String,
+}
+
+impl<'a, V> Iter<'a, V> {
+    pub(super) fn new(start: Option<&'a Node<V>>, prefix: String) -> Self {
+        Iter { start, stack: Vec::new(), key: prefix }
+    }
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<(String, &'a V)> {
+        if let Some(start) = self.start.take() {
+            self.stack.push(start.children.iter());
+            if let Some(value) = &start.value {
+                return Some((self.key.clone(), value));
+            }
+        }
+        loop {
+            match self.stack.last_mut()?.next() {
+                Some((c, child)) => {
+                    self.key.push(*c);
+                    self.stack.push(child.children.iter());
+                    if let Some(value) = &child.value {
+                        return Some((self.key.clone(), value));
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                    // The children of the start node don't add a char to the key.
+                    if !self.stack.is_empty() {
+                        self.key.pop();
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<V> FusedIterator for Iter<'_, V> {}
+
+impl<V> Clone for Iter<'_, V> {
+    fn clone(&self) -> Self {
+        Iter { start: self.start, stack: self.stack.clone(), key: self.key.clone() }
+    }
+}
+
+/// An iterator over the keys of a `Trie`, in lexicographic order.
+///
+/// Created by `Trie::keys` and `Trie::starts_with`.
+pub struct Keys<'a, V> {
+    pub(super) iter: Iter<'a, V>,
+}
+
+impl<V> Iterator for Keys<'_, V> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.iter.next().map(|(key, _)| key)
+    }
+}
+
+impl<V> FusedIterator for Keys<'_, V> {}
@@ -0,0 +1,233 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Debug, Display},
+    str::Chars,
+};
+
+pub mod iter;
+use iter::{Iter, Keys};
+
+#[derive(Clone)]
+struct Node<V> {
+    value: Option<V>,
+    children: BTreeMap<char, Node<V>>,
+}
+
+impl<V> Node<V> {
+    fn new() -> Self {
+        Node { value: None, children: BTreeMap::new() }
+    }
+}
+
+/// Removes the rest of a key from the subtree of `node` and prunes the branches that are left
+/// without any key.
+fn remove<V>(node: &mut Node<V>, mut chars: Chars) -> Option<V> {
+    let c = match chars.next() {
+        None => return node.value.take(),
+        Some(c) => c,
+    };
+    let child = node.children.get_mut(&c)?;
+    let value = remove(child, chars);
+    if child.value.is_none() && child.children.is_empty() {
+        node.children.remove(&c);
+    }
+    value
+}
+
+/// A map from strings to values, stored as a prefix tree.
+///
+/// Each node stands for a prefix and has one child per `char` that can follow it, so keys that
+/// share a prefix share the nodes along it. Lookups take time proportional to the length of the
+/// key, whatever the number of keys, and every key starting with a given prefix can be listed
+/// by walking a single subtree, which makes it handy for autocompletion. Children are kept
+/// sorted, so keys come out in lexicographic order.
+///
+/// Keys are split into `char`s rather than bytes, so non-ASCII strings are handled correctly.
+///
+/// # Type parameters
+///
+/// * `V`: The type of the value stored with each key.
+///
+/// # Examples
+///
+/// ```
+/// use basic_data_structures::trie::Trie;
+///
+/// let mut trie = Trie::new();
+/// trie.insert("canción", 1);
+/// trie.insert("cantar", 2);
+/// trie.insert("casa", 3);
+///
+/// let matches: Vec<String> = trie.starts_with("can").collect();
+/// assert_eq!(matches, vec!["canción", "cantar"]);
+/// assert_eq!(trie.get("casa"), Some(&3));
+/// assert_eq!(trie.longest_common_prefix(), "ca");
+/// ```
+#[derive(Clone)]
+pub struct Trie<V> {
+    root: Node<V>,
+    size: usize,
+}
+
+impl<V> Trie<V> {
+    /// Creates a new, empty trie.
+    pub fn new() -> Self {
+        Trie { root: Node::new(), size: 0 }
+    }
+
+    /// Returns the number of keys in the trie.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Checks whether the trie is empty.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Removes every key from the trie.
+    pub fn clear(&mut self) {
+        self.root = Node::new();
+        self.size = 0;
+    }
+
+    /// Inserts a key/value pair into the trie.
+    ///
+    /// # Returns
+    ///
+    /// The previous value of `key`, if it was already in the trie.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.entry(c).or_insert_with(Node::new);
+        }
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.size += 1;
+        }
+        old
+    }
+
+    /// Returns a reference to the value associated with `key`.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.find(key)?.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value associated with `key`.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.get_mut(&c)?;
+        }
+        node.value.as_mut()
+    }
+
+    /// Returns `true` if `key` is in the trie.
+    pub fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes `key` from the trie, along with the nodes that no other key uses.
+    ///
+    /// # Returns
+    ///
+    /// The value that was associated with `key`, if any.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let value = remove(&mut self.root, key.chars())?;
+        self.size -= 1;
+        Some(value)
+    }
+
+    /// Returns an iterator over the keys that start with `prefix`, in lexicographic order.
+    ///
+    /// `prefix` itself is included if it is a key.
+    pub fn starts_with(&self, prefix: &str) -> Keys<'_, V> {
+        Keys { iter: self.with_prefix(prefix) }
+    }
+
+    /// Returns an iterator over the keys that start with `prefix` and their values, in
+    /// lexicographic order of the keys.
+    pub fn with_prefix(&self, prefix: &str) -> Iter<'_, V> {
+        Iter::new(self.find(prefix), prefix.to_string())
+    }
+
+    /// Returns the longest prefix that every key of the trie starts with. It is empty if the
+    /// trie is.
+    pub fn longest_common_prefix(&self) -> String {
+        let mut prefix = String::new();
+        let mut node = &self.root;
+        // The walk stops at the first key, since it can't be followed by anything shared, or
+        // at the first fork.
+        while node.value.is_none() && node.children.len() == 1 {
+            let (c, child) = node.children.iter().next().expect("the node has one child");
+            prefix.push(*c);
+            node = child;
+        }
+        prefix
+    }
+
+    /// Returns an iterator over the keys of the trie and their values, in lexicographic order
+    /// of the keys.
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter::new(Some(&self.root), String::new())
+    }
+
+    /// Returns an iterator over the keys of the trie, in lexicographic order.
+    pub fn keys(&self) -> Keys<'_, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Returns the node of `prefix`, if any key starts with it.
+    fn find(&self, prefix: &str) -> Option<&Node<V>> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Debug> Debug for Trie<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V: Display> Trie<V> {
+    pub fn print(&self) {
+        let entries: Vec<String> =
+            self.iter().map(|(key, value)| format!("{}: {}", key, value)).collect();
+        println!("{{{}}}", entries.join(", "));
+    }
+}
+
+impl<K: AsRef<str>, V> FromIterator<(K, V)> for Trie<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut trie = Self::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<K: AsRef<str>, V> Extend<(K, V)> for Trie<V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key.as_ref(), value);
+        }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a Trie<V> {
+    type Item = (String, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Iter<'a, V> {
+        self.iter()
+    }
+}
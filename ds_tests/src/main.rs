@@ -1,10 +1,83 @@
-use basic_data_structures::{hash_table::HashTable};
+use std::{env, time::Instant};
+
+use basic_data_structures::hash_table::{HashTable, OpenHashTable};
+
+/// The operations both hash tables share, so the comparison can run the same code on each.
+trait Table {
+    fn insert(&mut self, key: String, value: usize) -> Option<usize>;
+    fn get(&self, key: &str) -> Option<&usize>;
+}
+
+impl Table for HashTable<String, usize> {
+    fn insert(&mut self, key: String, value: usize) -> Option<usize> {
+        HashTable::insert(self, key, value)
+    }
+    fn get(&self, key: &str) -> Option<&usize> {
+        HashTable::get(self, key)
+    }
+}
+
+impl Table for OpenHashTable<String, usize> {
+    fn insert(&mut self, key: String, value: usize) -> Option<usize> {
+        OpenHashTable::insert(self, key, value)
+    }
+    fn get(&self, key: &str) -> Option<&usize> {
+        OpenHashTable::get(self, key)
+    }
+}
+
+/// Inserts every key, then looks up every key plus as many missing ones.
+///
+/// Returns the milliseconds spent inserting, the milliseconds spent looking up and the sum of
+/// every value found, which must match between tables.
+fn run(table: &mut dyn Table, keys: &[String], missing: &[String]) -> (f64, f64, usize) {
+    let start = Instant::now();
+    for (i, key) in keys.iter().enumerate() {
+        table.insert(key.clone(), i);
+    }
+    let insert_time = start.elapsed().as_secs_f64() * 1000.0;
+
+    let start = Instant::now();
+    let mut checksum = 0;
+    for key in keys.iter().chain(missing.iter()) {
+        if let Some(value) = table.get(key) {
+            checksum += value;
+        }
+    }
+    let lookup_time = start.elapsed().as_secs_f64() * 1000.0;
+
+    (insert_time, lookup_time, checksum)
+}
+
+fn compare() {
+    println!("\n{:>8} | {:<10} | {:>12} | {:>12}", "keys", "table", "insert (ms)", "lookup (ms)");
+    for count in [1_000, 10_000, 100_000] {
+        let keys: Vec<String> = (0..count).map(|i| format!("key-{}", i)).collect();
+        let missing: Vec<String> = (0..count).map(|i| format!("missing-{}", i)).collect();
+
+        let (chaining_insert, chaining_lookup, chaining_sum) =
+            run(&mut HashTable::new(), &keys, &missing);
+        let (open_insert, open_lookup, open_sum) = run(&mut OpenHashTable::new(), &keys, &missing);
+        assert_eq!(chaining_sum, open_sum, "both tables must find the same values");
+
+        println!(
+            "{:>8} | {:<10} | {:>12.3} | {:>12.3}",
+            count, "chaining", chaining_insert, chaining_lookup
+        );
+        println!("{:>8} | {:<10} | {:>12.3} | {:>12.3}", count, "open", open_insert, open_lookup);
+    }
+}
 
 fn main() {
+    if env::args().any(|arg| arg == "compare") {
+        compare();
+        return;
+    }
+
     let mut table = HashTable::new_with_custom_size(20);
 
     for i in 0..200 {
-        table.insert(i.to_string());
+        table.insert(i.to_string(), i);
     }
 
     table.print();